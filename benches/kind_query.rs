@@ -0,0 +1,81 @@
+//! Compares the cost of iterating entities by [`EntityKind`] against a plain component filter.
+
+use bevy_ecs::prelude::*;
+use bevy_kindly::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const ENTITY_COUNT: usize = 10_000;
+
+#[derive(EntityKind)]
+#[components(Speed, Clearance)]
+struct Agent(Entity);
+
+#[derive(Component)]
+struct Speed(f64);
+
+#[derive(Component)]
+struct Clearance(usize);
+
+#[derive(Component)]
+struct Marker;
+
+#[derive(Component)]
+struct Fragment<const N: usize>;
+
+/// Spawns agents spread across `fragments` archetypes.
+fn spawn(world: &mut World, fragments: usize) {
+    for i in 0..ENTITY_COUNT {
+        let mut entity =
+            world.spawn((KindBundle::<Agent>::new((Speed(1.0), Clearance(1))), Marker));
+        match i % fragments {
+            0 => {}
+            1 => {
+                entity.insert(Fragment::<1>);
+            }
+            2 => {
+                entity.insert(Fragment::<2>);
+            }
+            _ => {
+                entity.insert((Fragment::<1>, Fragment::<2>));
+            }
+        }
+    }
+}
+
+fn iterate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate");
+    for fragments in [1, 2, 4] {
+        let mut world = World::new();
+        spawn(&mut world, fragments);
+
+        let mut with_kind = world.query::<EntityWithKind<Agent>>();
+        group.bench_with_input(
+            BenchmarkId::new("with_kind", fragments),
+            &fragments,
+            |b, _| {
+                b.iter(|| {
+                    with_kind.iter(&world).for_each(|item| {
+                        black_box(item.entity());
+                    })
+                })
+            },
+        );
+
+        let mut with_component = world.query_filtered::<Entity, With<Marker>>();
+        group.bench_with_input(
+            BenchmarkId::new("with_component", fragments),
+            &fragments,
+            |b, _| {
+                b.iter(|| {
+                    with_component.iter(&world).for_each(|entity| {
+                        black_box(entity);
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, iterate);
+criterion_main!(benches);
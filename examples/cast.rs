@@ -7,7 +7,7 @@
 //! NOTE: It is recommended to understand the `multiple.rs` example before going through this one.
 
 use bevy::prelude::*;
-use bevy_kindly::*;
+use bevy_kindly::prelude::*;
 
 /// Same as `Person` from `person.rs` example, except it uses a named `PersonBundle` to define
 /// the components of a `Person`.
@@ -57,13 +57,13 @@ struct Position(Vec2);
 struct Destination(Position);
 
 trait NavigateTo {
-    fn navigate_to(self, position: Position);
+    fn navigate_to(self, position: Position) -> Self;
 }
 
 /// Only agents can have a navigation request.
 impl NavigateTo for &mut EntityKindCommands<'_, '_, '_, Agent> {
-    fn navigate_to(self, position: Position) {
-        self.insert(Destination(position));
+    fn navigate_to(self, position: Position) -> Self {
+        self.insert(Destination(position))
     }
 }
 
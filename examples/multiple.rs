@@ -6,7 +6,8 @@
 //! before going through this one.
 
 use bevy::prelude::*;
-use bevy_kindly::*;
+use bevy_kindly::{assert_invariants, KindWorld};
+use bevy_kindly::prelude::*;
 
 /// Same as `Person` from `person.rs` example, except it uses a named `PersonBundle` to define
 /// the components of a `Person`.
@@ -51,6 +52,9 @@ struct Position(Vec2);
 fn main() {
     let mut world = World::default();
 
+    // Register both kinds, since `KindBundle<Agent>` is never inserted directly, only as part of `PersonBundle`
+    world.register_kind::<Person>().register_kind::<Agent>();
+
     // Spawn a Person through direct kind bundle insertion
     let entity = world
         .spawn(KindBundle::<Person>::new(PersonBundle {
@@ -59,15 +63,7 @@ fn main() {
             agent: KindBundle::<Agent>::new((Speed(10.0), Clearance(2))),
         })).id();
 
-    // Ensure the entity is both a Person and an Agent
-    assert!(world.entity(entity).try_with_kind::<Person>().is_some());
-    assert!(world.entity(entity).try_with_kind::<Agent>().is_some());
-
-    // Ensure all required components exist on the person
-    assert!(world.entity(entity).contains::<Friends>());
-    assert!(world.entity(entity).contains::<Name>());
-    assert!(world.entity(entity).contains::<Age>());
-    assert!(world.entity(entity).contains::<Position>());
-    assert!(world.entity(entity).contains::<Speed>());
-    assert!(world.entity(entity).contains::<Clearance>());
+    // Ensure the entity is both a Person and an Agent, with all of their required components
+    assert_invariants::<Person>(&world, entity);
+    assert_invariants::<Agent>(&world, entity);
 }
@@ -4,7 +4,7 @@
 //! Each [`EntityKind`] can be queried by systems in order to filter entities at runtime.
 
 use bevy::prelude::*;
-use bevy_kindly::*;
+use bevy_kindly::prelude::*;
 
 /// A navigation `Agent` is a kind of entity.
 /// Each navigation agent must have some `Speed`, and `Clearance`.
@@ -27,13 +27,13 @@ struct Destination(Position);
 
 /// Extension trait used to set a navigation destination on an agent.
 trait NavigateTo {
-    fn navigate_to(self, position: Position);
+    fn navigate_to(self, position: Position) -> Self;
 }
 
 /// Only agents can have a navigation request.
 impl NavigateTo for &mut EntityKindCommands<'_, '_, '_, Agent> {
-    fn navigate_to(self, position: Position) {
-        self.insert(Destination(position));
+    fn navigate_to(self, position: Position) -> Self {
+        self.insert(Destination(position))
     }
 }
 
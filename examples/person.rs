@@ -5,7 +5,7 @@
 //! all the required components.
 
 use bevy::prelude::*;
-use bevy_kindly::*;
+use bevy_kindly::prelude::*;
 
 /// A `Person` is a kind of entity.
 /// Deriving `Clone`, `Copy`, `PartialEq`, and `Eq` is not required, but it's often convenient.
@@ -23,17 +23,16 @@ struct Age(u32);
 // Some commands only `Person` entities can invoke:
 trait PersonCommands {
     // Only people can be friends with each other
-    fn add_friend(self, friend: Person);
+    fn add_friend(self, friend: Person) -> Self;
 }
 
 impl PersonCommands for &mut EntityKindCommands<'_, '_, '_, Person> {
-    fn add_friend(self, friend: Person) {
-        let person = self.get();
-        self.commands().add(move |world: &mut World| {
+    fn add_friend(self, friend: Person) -> Self {
+        self.queue(move |person, world| {
             // These unwraps are safe(er), because every `Person` entity has a `Friends` component
             world.get_mut::<Friends>(person.entity()).unwrap().0.push(friend);
             world.get_mut::<Friends>(friend.entity()).unwrap().0.push(person);
-        });
+        })
     }
 }
 
@@ -1,10 +1,23 @@
-use std::fmt::{Debug, Formatter, Result as FormatResult};
-use std::marker::PhantomData;
+use core::any::{type_name, TypeId};
+use core::fmt::{Debug, Display, Formatter, Result as FormatResult};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::panic::Location;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
 
+#[cfg(feature = "app")]
+use bevy_app::prelude::*;
+use bevy_ecs::bundle::DynamicBundle;
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::entity::EntityMapper;
 use bevy_ecs::prelude::*;
-use bevy_ecs::query::WorldQuery;
-use bevy_ecs::system::EntityCommands;
-use bevy_ecs::world::EntityRef;
+use bevy_ecs::query::{QuerySingleError, ReadOnlyWorldQuery, WorldQuery};
+use bevy_ecs::system::{EntityCommands, SystemParam};
+use bevy_ecs::world::{EntityMut, EntityRef};
+#[cfg(feature = "hierarchy")]
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent};
+use bevy_utils::tracing::{info, warn};
 
 pub use bevy_kindly_macros::EntityKind;
 
@@ -22,6 +35,11 @@ pub trait EntityKind: 'static + Send + Sync {
     ///
     type Bundle: Bundle;
 
+    ///
+    /// Optional [`KindMetadata`] associated with this [`EntityKind`].
+    ///
+    const METADATA: KindMetadata = KindMetadata::EMPTY;
+
     ///
     /// Creates a new [`Entity`] with this [`EntityKind`].
     ///
@@ -35,41 +53,157 @@ pub trait EntityKind: 'static + Send + Sync {
     /// Returns this [`EntityKind`] as a generic [`Entity`].
     ///
     fn entity(&self) -> Entity;
+
+    ///
+    /// Returns true if this [`EntityKind`] and `other` refer to the same [`Entity`].
+    ///
+    /// This is the canonical comparison between kinds, independent of any other fields.
+    ///
+    fn eq_entity(&self, other: &Self) -> bool {
+        self.entity() == other.entity()
+    }
+
+    ///
+    /// Spawns a new [`Entity`] with this [`EntityKind`] and returns an [`EntityKindCommands`] for it.
+    ///
+    /// Same as [`KindCommands::spawn_with_kind`].
+    ///
+    #[track_caller]
+    fn spawn<'w, 's, 'a>(
+        commands: &'a mut Commands<'w, 's>,
+        bundle: Self::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, Self>
+    where
+        Self: Sized,
+    {
+        commands.spawn_with_kind(bundle)
+    }
+
+    ///
+    /// Spawns a new [`Entity`] with this [`EntityKind`] directly in the given [`World`] and returns it.
+    ///
+    /// This is mostly useful in tests, where [`Commands`] are not readily available.
+    ///
+    #[track_caller]
+    fn spawn_in(world: &mut World, bundle: Self::Bundle) -> Self
+    where
+        Self: Sized,
+    {
+        let entity = world.spawn(KindBundle::<Self>::new(bundle)).id();
+        // SAFE: `KindBundle` was just inserted
+        unsafe { Self::from_entity_unchecked(entity) }
+    }
+
+    ///
+    /// Returns the [`ComponentId`] of the [`Kind`] marker of this [`EntityKind`] in the given [`World`],
+    /// initializing it if needed.
+    ///
+    fn marker_component_id(world: &mut World) -> ComponentId
+    where
+        Self: Sized,
+    {
+        world.init_component::<Kind<Self>>()
+    }
+
+    ///
+    /// Returns the given [`Entity`] as this [`EntityKind`] if it has this kind and all of its components
+    /// in the given [`World`], or a [`KindError`] describing why it does not.
+    ///
+    fn from_world_entity(world: &World, entity: Entity) -> Result<Self, KindError>
+    where
+        Self: Sized,
+    {
+        check_invariants::<Self>(world, entity)?;
+        // SAFE: Entity kind and components were just checked
+        Ok(unsafe { Self::from_entity_unchecked(entity) })
+    }
+
+    ///
+    /// Returns this entity as another [`EntityKind`] if it has that kind in the given [`World`].
+    ///
+    fn downcast_kind<U: EntityKind>(&self, world: &World) -> Option<U> {
+        world.get_entity(self.entity())?.try_with_kind()
+    }
+}
+
+///
+/// Static metadata associated with an [`EntityKind`], readable without a [`World`].
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KindMetadata {
+    ///
+    /// Optional display name of the kind, used by tools such as inspectors.
+    ///
+    pub display_name: Option<&'static str>,
+
+    ///
+    /// Optional category of the kind, used to group related kinds together.
+    ///
+    pub category: Option<&'static str>,
+}
+
+impl KindMetadata {
+    ///
+    /// [`KindMetadata`] with no values set.
+    ///
+    pub const EMPTY: Self = Self {
+        display_name: None,
+        category: None,
+    };
 }
 
 ///
 /// A [`Bundle`] inserted into all entities of given [`EntityKind`].
 ///
+/// In debug builds with the `provenance` feature enabled, this bundle also includes a [`KindProvenance`]
+/// which records where it was created.
+///
 #[derive(Bundle)]
 pub struct KindBundle<T: EntityKind> {
     kind: Kind<T>,
     default_bundle: T::DefaultBundle,
     bundle: T::Bundle,
+    #[cfg(all(feature = "provenance", debug_assertions))]
+    provenance: KindProvenance<T>,
 }
 
 impl<T: EntityKind> KindBundle<T> {
     ///
     /// Creates a new [`KindBundle`] with given [`EntityKind::Bundle`].
     ///
+    #[track_caller]
     pub fn new(bundle: T::Bundle) -> Self {
         Self {
-            kind: Kind::default(),
+            kind: Kind::new(),
             default_bundle: T::DefaultBundle::default(),
             bundle,
+            #[cfg(all(feature = "provenance", debug_assertions))]
+            provenance: KindProvenance::new(Location::caller()),
         }
     }
+
+    ///
+    /// Returns the [`EntityKind::Bundle`] of this [`KindBundle`].
+    ///
+    pub fn bundle(&self) -> &T::Bundle {
+        &self.bundle
+    }
+
+    ///
+    /// Returns the [`EntityKind::Bundle`] of this [`KindBundle`] as mutable.
+    ///
+    pub fn bundle_mut(&mut self) -> &mut T::Bundle {
+        &mut self.bundle
+    }
 }
 
 impl<T: EntityKind> Clone for KindBundle<T>
 where
     T::Bundle: Clone,
 {
+    #[track_caller]
     fn clone(&self) -> Self {
-        Self {
-            kind: Kind::default(),
-            default_bundle: T::DefaultBundle::default(),
-            bundle: self.bundle.clone(),
-        }
+        Self::new(self.bundle.clone())
     }
 }
 
@@ -77,6 +211,7 @@ impl<T: EntityKind> Default for KindBundle<T>
 where
     T::Bundle: Default,
 {
+    #[track_caller]
     fn default() -> Self {
         Self::new(T::Bundle::default())
     }
@@ -85,7 +220,8 @@ where
 ///
 /// A [`WorldQuery`] filter for entities with some given [`EntityKind`].
 ///
-/// Similar to [`With`] in usage.
+/// Similar to [`With`] in usage. It may also be composed with other filters, such as
+/// `Or<(WithKind<A>, WithKind<B>)>`, to match entities with any of the given kinds.
 ///
 #[derive(WorldQuery)]
 pub struct WithKind<T: EntityKind> {
@@ -102,10 +238,30 @@ pub struct AddedKind<T: EntityKind> {
     added_kind: Added<Kind<T>>,
 }
 
+///
+/// A [`SystemParam`] used to iterate entities which have lost some given [`EntityKind`], or were despawned.
+///
+/// Similar to [`RemovedComponents`] in usage.
+///
+#[derive(SystemParam)]
+pub struct RemovedKind<'w, 's, T: EntityKind> {
+    removed: RemovedComponents<'w, 's, Kind<T>>,
+}
+
+impl<T: EntityKind> RemovedKind<'_, '_, T> {
+    ///
+    /// Iterates over all entities which have lost the given [`EntityKind`] since this system last ran.
+    ///
+    pub fn iter(&mut self) -> impl Iterator<Item = Entity> + '_ {
+        self.removed.iter()
+    }
+}
+
 ///
 /// A [`WorldQuery`] used to query entities with some given [`EntityKind`].
 ///
-/// Similar to [`Entity`] in usage.
+/// Similar to [`Entity`] in usage. This query is read-only, but it may be combined with mutable queries,
+/// such as `(EntityWithKind<T>, &mut C)`, without affecting their mutability.
 ///
 #[derive(WorldQuery)]
 pub struct EntityWithKind<T: EntityKind> {
@@ -128,6 +284,25 @@ impl<T: EntityKind> EntityWithKindItem<'_, T> {
         // SAFE: `EntityWithKind` ensures entity has correct kind
         unsafe { T::from_entity_unchecked(self.entity) }
     }
+
+    ///
+    /// Converts this [`EntityWithKindItem`] into its [`EntityKind`].
+    ///
+    /// Useful for collecting query results, such as `query.iter().map(EntityWithKindItem::into_kind)`.
+    ///
+    pub fn into_kind(self) -> T {
+        self.get()
+    }
+
+    ///
+    /// Returns an [`EntityRef`] for this [`EntityWithKindItem`] from the given [`World`].
+    ///
+    /// Note that a system may only access `&World` alongside read-only queries, since `&World` conflicts with
+    /// any mutable component access.
+    ///
+    pub fn as_entity_ref<'w>(&self, world: &'w World) -> EntityRef<'w> {
+        world.entity(self.entity)
+    }
 }
 
 impl<T: EntityKind> PartialEq<T> for EntityWithKindItem<'_, T> {
@@ -142,9 +317,71 @@ impl<T: EntityKind + Debug> Debug for EntityWithKindItem<'_, T> {
     }
 }
 
+///
+/// A [`WorldQuery`] used to query entities with some given [`EntityKind`], along with change detection for it.
+///
+/// Similar to [`EntityWithKind`], except it also reads the change ticks of the [`Kind`] marker.
+/// Prefer [`EntityWithKind`] unless [`EntityWithKindRefItem::is_newly_kind`] is needed.
+///
+#[derive(WorldQuery)]
+pub struct EntityWithKindRef<T: EntityKind> {
+    entity: Entity,
+    kind: Ref<'static, Kind<T>>,
+}
+
+impl<T: EntityKind> EntityWithKindRefItem<'_, T> {
+    ///
+    /// Returns this [`EntityWithKindRefItem`] as a generic [`Entity`].
+    ///
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    ///
+    /// Returns the [`EntityKind`] from query.
+    ///
+    pub fn get(&self) -> T {
+        // SAFE: `EntityWithKindRef` ensures entity has correct kind
+        unsafe { T::from_entity_unchecked(self.entity) }
+    }
+
+    ///
+    /// Returns true if this entity was given its [`EntityKind`] since the system last ran.
+    ///
+    /// Similar to [`AddedKind`], but without filtering the query.
+    ///
+    pub fn is_newly_kind(&self) -> bool {
+        self.kind.is_added()
+    }
+}
+
+///
+/// Extension trait used to get a single [`EntityKind`] from a [`Query`] of [`EntityWithKind`].
+///
+pub trait GetSingleKind<T: EntityKind> {
+    ///
+    /// Returns the only [`EntityKind`] matched by this query, or a [`QuerySingleError`] if there is not exactly one.
+    ///
+    fn get_single_kind(&self) -> Result<T, QuerySingleError>;
+}
+
+impl<T: EntityKind, F: ReadOnlyWorldQuery> GetSingleKind<T>
+    for Query<'_, '_, EntityWithKind<T>, F>
+{
+    fn get_single_kind(&self) -> Result<T, QuerySingleError> {
+        self.get_single().map(|item| item.get())
+    }
+}
+
 ///
 /// A wrapper for [`EntityCommands`] to execute commands on entities with a specific [`EntityKind`].
 ///
+/// To return an entity from a function without its borrowed lifetimes, return the [`EntityKind`] itself
+/// (using [`EntityKindCommands::get`]) and re-attach it to some [`Commands`] later using [`KindCommands::with_kind`].
+///
+/// By convention, methods of extension traits implemented for `&mut EntityKindCommands` should return `Self`,
+/// so that they may be chained fluently with other commands.
+///
 pub struct EntityKindCommands<'w, 's, 'a, T: EntityKind>(
     EntityCommands<'w, 's, 'a>,
     PhantomData<T>,
@@ -172,11 +409,30 @@ impl<'w, 's, 'a, T: EntityKind> EntityKindCommands<'w, 's, 'a, T> {
     ///
     /// Returns the associated [`EntityKind`].
     ///
+    /// Note that this does not check if the kind was removed by any previously queued commands.
+    /// Use [`EntityKindCommands::try_get`] where [`World`] access is available.
+    ///
     pub fn get(&self) -> T {
         // SAFE: `EntityKindCommands<T>` is always associated with an entity of matching kind
         unsafe { T::from_entity_unchecked(self.entity()) }
     }
 
+    ///
+    /// Returns the associated [`EntityKind`] if the entity still has it in the given [`World`].
+    ///
+    pub fn try_get(&self, world: &World) -> Option<T> {
+        world.get_entity(self.entity())?.try_with_kind()
+    }
+
+    ///
+    /// Returns a new [`EntityKindCommands`] which borrows this one.
+    ///
+    pub fn reborrow(&mut self) -> EntityKindCommands<'w, 's, '_, T> {
+        let entity = self.entity();
+        // SAFE: `EntityKindCommands<T>` is always associated with an entity of matching kind
+        unsafe { EntityKindCommands::from_entity_unchecked(self.commands().entity(entity)) }
+    }
+
     ///
     /// Returns the underlying [`Commands`].
     ///
@@ -196,111 +452,2128 @@ impl<'w, 's, 'a, T: EntityKind> EntityKindCommands<'w, 's, 'a, T> {
         self
     }
 
-    pub fn remove<S: Component>(&mut self) -> &mut Self {
-        self.0.remove::<S>();
+    ///
+    /// Inserts a [`Component`] into the associated [`Entity`] only if it does not already have it.
+    ///
+    pub fn insert_if_new<C: Component>(&mut self, component: C) -> &mut Self {
+        self.0.add(move |entity: Entity, world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if !entity.contains::<C>() {
+                entity.insert(component);
+            }
+        });
         self
     }
-}
 
-///
-/// Extension trait used to insert a new [`EntityKind`] into any [`Entity`] using some [`EntityCommands`].
-///
-pub trait InsertKind<'w, 's, 'a> {
     ///
-    /// Inserts a new [`EntityKind`] into the associated [`Entity`] and returns an [`EntityKindCommands`] for it.
+    /// Inserts a [`Bundle`] into the associated [`Entity`] only if it has none of its components.
     ///
-    fn insert_kind<T: EntityKind>(self, bundle: T::Bundle) -> EntityKindCommands<'w, 's, 'a, T>;
-}
+    /// This is all-or-nothing: if the entity has any component of the bundle when the commands are applied,
+    /// the entire bundle is discarded. This allows multiple systems to race to insert the same bundle,
+    /// with the first one winning.
+    ///
+    pub fn insert_bundle_if_absent<B: Bundle>(&mut self, bundle: B) -> &mut Self {
+        self.0.add(move |entity: Entity, world: &mut World| {
+            if world.get_entity(entity).is_none() {
+                return;
+            }
+            let component_ids = bundle_components::<B>(world);
+            let mut entity = world.entity_mut(entity);
+            if !component_ids
+                .iter()
+                .any(|&component_id| entity.contains_id(component_id))
+            {
+                entity.insert(bundle);
+            }
+        });
+        self
+    }
 
-impl<'w, 's, 'a> InsertKind<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
-    fn insert_kind<T: EntityKind>(
-        mut self,
-        bundle: T::Bundle,
-    ) -> EntityKindCommands<'w, 's, 'a, T> {
-        self.insert(KindBundle::<T>::new(bundle));
-        // SAFE: `KindBundle` was just inserted
-        unsafe { EntityKindCommands::from_entity_unchecked(self) }
+    ///
+    /// Inserts a [`Component`] into the associated [`Entity`] if the given condition is true.
+    ///
+    pub fn insert_if(&mut self, condition: bool, component: impl Component) -> &mut Self {
+        if condition {
+            self.0.insert(component);
+        }
+        self
     }
-}
 
-///
-/// Extension trait which provides [`EntityKind`] support for [`Commands`].
-///
-pub trait KindCommands<'w, 's, 'a> {
     ///
-    /// Spawns a new [`Entity`] with given [`EntityKind`] and returns an [`EntityKindCommands`] for it.
+    /// Inserts a [`Component`] created by the given function into the associated [`Entity`] if the given
+    /// condition is true.
     ///
-    fn spawn_with_kind<T: EntityKind>(self, bundle: T::Bundle)
-        -> EntityKindCommands<'w, 's, 'a, T>;
+    /// Unlike [`EntityKindCommands::insert_if`], the component is only created if the condition is true,
+    /// which avoids constructing expensive components when they are not needed.
+    ///
+    pub fn insert_with<C: Component>(
+        &mut self,
+        condition: bool,
+        f: impl FnOnce() -> C,
+    ) -> &mut Self {
+        if condition {
+            self.0.insert(f());
+        }
+        self
+    }
 
     ///
-    /// Returns a new [`EntityKindCommands`] for some [`EntityKind`].
+    /// Removes a [`Component`] from the associated [`Entity`].
     ///
-    fn with_kind<T: EntityKind>(self, kind: &T) -> EntityKindCommands<'w, 's, 'a, T>;
-}
+    /// Components required by this [`EntityKind`] are not removed. Instead, this panics in debug builds,
+    /// and logs a warning otherwise. Use [`EntityKindCommands::force_remove`] to remove such components intentionally.
+    /// Since this is only checked when the commands are applied, the message includes the location of this call.
+    ///
+    #[track_caller]
+    pub fn remove<S: Component>(&mut self) -> &mut Self {
+        let location = Location::caller();
+        self.0.add(move |entity: Entity, world: &mut World| {
+            remove_unrequired::<T, S>(entity, world, location);
+        });
+        self
+    }
 
-impl<'w, 's, 'a> KindCommands<'w, 's, 'a> for &'a mut Commands<'w, 's> {
-    fn spawn_with_kind<T: EntityKind>(
-        self,
-        bundle: T::Bundle,
-    ) -> EntityKindCommands<'w, 's, 'a, T> {
-        self.spawn_empty().insert_kind(bundle)
+    ///
+    /// Removes a [`Component`] from the associated [`Entity`], even if it is required by this [`EntityKind`].
+    ///
+    /// Removing such components breaks the invariants of this [`EntityKind`].
+    ///
+    pub fn force_remove<S: Component>(&mut self) -> &mut Self {
+        self.0.remove::<S>();
+        self
     }
 
-    fn with_kind<T: EntityKind>(self, kind: &T) -> EntityKindCommands<'w, 's, 'a, T> {
-        // SAFE: `kind` may only reference an entity with correct kind
-        unsafe { EntityKindCommands::from_entity_unchecked(self.entity(kind.entity())) }
+    ///
+    /// Inserts the components of another [`EntityKind`] into the associated [`Entity`] without marking it with that kind.
+    ///
+    /// Note that the entity will **not** match any kind filters (such as [`WithKind`]) for the given kind.
+    ///
+    pub fn insert_kind_components<U: EntityKind>(&mut self, bundle: U::Bundle) -> &mut Self {
+        self.0.insert((U::DefaultBundle::default(), bundle));
+        self
     }
-}
 
-///
-/// Extension trait used to safely cast an [`Entity`] into an [`EntityKind`].
-///
-pub trait TryWithKind {
     ///
-    /// Checks if this [`Entity`] has the given [`EntityKind`] and returns it.
+    /// Inserts a prebuilt [`KindBundle`] of another [`EntityKind`] into the associated [`Entity`].
     ///
-    fn try_with_kind<T: EntityKind>(self) -> Option<T>;
-}
+    /// The entity keeps this kind, in addition to the inserted kind.
+    ///
+    pub fn insert_kind_bundle<U: EntityKind>(&mut self, bundle: KindBundle<U>) -> &mut Self {
+        self.0.insert(bundle);
+        self
+    }
 
-impl TryWithKind for &EntityRef<'_> {
-    fn try_with_kind<T: EntityKind>(self) -> Option<T> {
-        self.contains::<Kind<T>>()
-            // SAFE: Entity kind was just checked
-            .then(|| unsafe { T::from_entity_unchecked(self.id()) })
+    ///
+    /// Sets the [`Parent`] of the associated [`Entity`] to the given [`EntityKind`].
+    ///
+    #[cfg(feature = "hierarchy")]
+    pub fn set_container<C: EntityKind>(&mut self, parent: &C) -> &mut Self {
+        self.0.set_parent(parent.entity());
+        self
     }
-}
 
-///
-/// A [`Component`] which marks an [`Entity`] as having a given [`EntityKind`].
-///
-#[derive(Component)]
-struct Kind<T: EntityKind>(PhantomData<T>);
+    ///
+    /// Sets the [`Parent`] of the associated [`Entity`] to the given [`EntityKind`], and adds this
+    /// [`EntityKind`] to the [`KindVec`] of the parent, if it has one.
+    ///
+    #[cfg(feature = "hierarchy")]
+    pub fn add_to_kind<C: EntityKind>(&mut self, parent: &C) -> &mut Self {
+        self.set_container(parent);
+        let parent = parent.entity();
+        self.queue(move |kind, world| {
+            if let Some(mut kinds) = world.get_mut::<KindVec<T>>(parent) {
+                if !kinds.contains(&kind) {
+                    kinds.push(kind);
+                }
+            }
+        })
+    }
 
-impl<T: EntityKind> Default for Kind<T> {
-    fn default() -> Self {
-        Self(PhantomData)
+    ///
+    /// Despawns all descendants of the associated [`Entity`], without despawning the entity itself.
+    ///
+    #[cfg(feature = "hierarchy")]
+    pub fn despawn_descendants(&mut self) -> &mut Self {
+        self.0.despawn_descendants();
+        self
     }
-}
 
-pub mod utils {
-    use bevy_ecs::prelude::*;
-    use bevy_ecs::system::CommandQueue;
+    ///
+    /// Removes all other registered [`EntityKind`] markers and their components from the associated [`Entity`].
+    ///
+    /// Only kinds registered in the [`KindRegistry`] are removed. Components shared with this kind are preserved.
+    /// Note that the entity is re-marked with this kind, so it will match [`AddedKind`] again.
+    ///
+    pub fn retain_kind(&mut self) -> &mut Self {
+        self.0.add(retain_kind::<T>);
+        self
+    }
 
     ///
-    /// Extension trait to execute [`Commands`] on a [`World`].
+    /// Calls `f` with mutable access to the associated [`Entity`] when the commands are applied.
     ///
-    pub trait Execute {
-        fn execute<F: FnOnce(&World, Commands) -> R, R>(self, f: F) -> R;
+    /// The function is only called if the entity still exists and has this [`EntityKind`],
+    /// so it may rely on the kind's components being present.
+    ///
+    pub fn entity_mut_scoped<F: FnOnce(&mut EntityMut) + Send + 'static>(
+        &mut self,
+        f: F,
+    ) -> &mut Self {
+        self.0.add(move |entity: Entity, world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if entity.contains::<Kind<T>>() {
+                f(&mut entity);
+            }
+        });
+        self
     }
 
-    impl Execute for &mut World {
-        fn execute<F: FnOnce(&World, Commands) -> R, R>(self, f: F) -> R {
-            let mut queue = CommandQueue::default();
-            let commands = Commands::new(&mut queue, self);
-            let result = f(self, commands);
-            queue.apply(self);
-            result
+    ///
+    /// Calls `f` with the associated [`EntityKind`] and the [`World`] when the commands are applied.
+    ///
+    pub fn queue<F: FnOnce(T, &mut World) + Send + 'static>(&mut self, f: F) -> &mut Self {
+        let kind = self.get();
+        self.commands().add(move |world: &mut World| f(kind, world));
+        self
+    }
+
+    ///
+    /// Logs the associated [`Entity`], the name of this [`EntityKind`], and which of its components are present.
+    ///
+    pub fn log_kind(&mut self) -> &mut Self {
+        self.0.add(log_kind::<T>);
+        self
+    }
+
+    ///
+    /// Replaces this [`EntityKind`] with another and returns an [`EntityKindCommands`] for it.
+    ///
+    /// Components of this kind are removed, unless they are also required by the new kind or by any other
+    /// kind of the entity registered in the [`KindRegistry`]. Components required by both kinds keep their values,
+    /// except for those given by the new [`EntityKind::Bundle`]. Existing [`EntityKind::DefaultBundle`] components
+    /// of the new kind are preserved.
+    ///
+    /// Note that kept components are taken and re-inserted when the commands are applied, so they are reported
+    /// as removed and added again (see [`RemovedComponents`] and [`Added`]).
+    ///
+    #[track_caller]
+    pub fn replace_kind<U: EntityKind>(
+        mut self,
+        bundle: U::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, U> {
+        let kind_bundle = KindBundle::<U>::new(bundle);
+        self.0.add(move |entity: Entity, world: &mut World| {
+            if world.get_entity(entity).is_none() {
+                return;
+            }
+            let mut required =
+                other_kind_components(world, entity, &[TypeId::of::<T>(), TypeId::of::<U>()]);
+            required.extend(bundle_components::<KindBundle<U>>(world));
+            if let Some(old_bundle) = world.entity_mut(entity).take::<KindBundle<T>>() {
+                insert_filtered(world, entity, old_bundle, |component_id| {
+                    required.contains(&component_id)
+                });
+            } else {
+                // Old kind is already missing some of its components, so only its marker is removed
+                world.entity_mut(entity).remove::<Kind<T>>();
+                #[cfg(all(feature = "provenance", debug_assertions))]
+                world.entity_mut(entity).remove::<KindProvenance<T>>();
+            }
+            insert_kind_bundle_preserving(world, entity, kind_bundle);
+        });
+        // SAFE: `KindBundle` is inserted along with this command
+        unsafe { EntityKindCommands::from_entity_unchecked(self.0) }
+    }
+
+    ///
+    /// Spawns a new [`Entity`] with a clone of this [`EntityKind`] and returns an [`EntityKindCommands`] for it.
+    ///
+    /// Only the [`EntityKind::Bundle`] is cloned, and the [`EntityKind::DefaultBundle`] is reset, same as
+    /// [`KindBundle::clone`]. Any other components are not copied.
+    ///
+    /// To clone the bundle, its components are taken from this entity and re-inserted when the commands are applied.
+    /// The [`Kind`] marker of this entity is untouched, but the bundle components themselves are reported as
+    /// removed and added again (see [`RemovedComponents`] and [`Added`]).
+    ///
+    /// If this entity no longer has this kind or its components by then, the clone is despawned instead.
+    /// Any commands queued for the clone afterwards, such as [`EntityKindCommands::insert`], will then panic
+    /// because the entity does not exist (see Bevy error B0003).
+    ///
+    #[track_caller]
+    pub fn clone_kind(&mut self) -> EntityKindCommands<'w, 's, '_, T>
+    where
+        T::Bundle: Clone,
+    {
+        #[cfg(all(feature = "provenance", debug_assertions))]
+        let location = Location::caller();
+        let clone = self.commands().spawn_empty().id();
+        self.0.add(move |entity: Entity, world: &mut World| {
+            let bundle = world
+                .get_entity_mut(entity)
+                .filter(|entity| entity.contains::<Kind<T>>())
+                .and_then(|mut entity| entity.take::<T::Bundle>());
+            let Some(bundle) = bundle else {
+                world.despawn(clone);
+                return;
+            };
+            let kind_bundle = KindBundle::<T>::new(bundle.clone());
+            #[cfg(all(feature = "provenance", debug_assertions))]
+            let kind_bundle = KindBundle {
+                provenance: KindProvenance::new(location),
+                ..kind_bundle
+            };
+            world.entity_mut(entity).insert(bundle);
+            world.entity_mut(clone).insert(kind_bundle);
+        });
+        // SAFE: `KindBundle` is inserted into the clone along with this command, or the clone is despawned
+        unsafe { EntityKindCommands::from_entity_unchecked(self.commands().entity(clone)) }
+    }
+}
+
+fn log_kind<T: EntityKind>(entity: Entity, world: &mut World) {
+    let Some(entity_ref) = world.get_entity(entity) else {
+        info!("{entity:?} of kind `{}` does not exist", kind_name::<T>());
+        return;
+    };
+    let has_kind = entity_ref.contains::<Kind<T>>();
+    let Some(component_ids) = kind_components::<T>(world) else {
+        info!(
+            "{entity:?} of kind `{}` (marker {}): [components unknown, kind is not registered]",
+            kind_name::<T>(),
+            if has_kind { "present" } else { "missing" },
+        );
+        return;
+    };
+    let components: Vec<_> = component_ids
+        .iter()
+        .map(|component_id| {
+            let name = world
+                .components()
+                .get_info(*component_id)
+                .map_or("<unknown>", |info| info.name());
+            let status = if entity_ref.contains_id(*component_id) {
+                "present"
+            } else {
+                "missing"
+            };
+            format!("{name}: {status}")
+        })
+        .collect();
+    info!(
+        "{entity:?} of kind `{}` (marker {}): [{}]",
+        kind_name::<T>(),
+        if has_kind { "present" } else { "missing" },
+        components.join(", ")
+    );
+}
+
+fn remove_unrequired<T: EntityKind, S: Component>(
+    entity: Entity,
+    world: &mut World,
+    location: &'static Location<'static>,
+) {
+    // Kinds which are not registered (see `KindWorld::register_kind`) are only checked once their
+    // bundle has been inserted directly, since nested bundles are not registered by Bevy
+    let required = world
+        .component_id::<S>()
+        .zip(kind_components::<T>(world))
+        .is_some_and(|(component_id, component_ids)| component_ids.contains(&component_id));
+    if required {
+        let message = format!(
+            "component `{}` is required by kind `{}`, use `force_remove` to remove it (removed at {location})",
+            type_name::<S>(),
+            kind_name::<T>(),
+        );
+        if cfg!(debug_assertions) {
+            panic!("{message}");
+        }
+        warn!("{message}");
+        return;
+    }
+    if let Some(mut entity) = world.get_entity_mut(entity) {
+        entity.remove::<S>();
+    }
+}
+
+///
+/// Returns the name of the first of the given components which is missing from the given [`Entity`].
+///
+fn find_missing_component<'w>(
+    world: &'w World,
+    entity: Entity,
+    component_ids: &[ComponentId],
+) -> Option<&'w str> {
+    let entity_ref = world.get_entity(entity)?;
+    let missing = component_ids
+        .iter()
+        .find(|component_id| !entity_ref.contains_id(**component_id))?;
+    Some(
+        world
+            .components()
+            .get_info(*missing)
+            .map_or("<unknown>", |info| info.name()),
+    )
+}
+
+///
+/// Returns the [`ComponentId`] of every component in the [`KindBundle`] of the given [`EntityKind`],
+/// or [`None`] if the bundle is not registered in the given [`World`].
+///
+/// Bevy only registers bundles which are inserted directly, and not those nested in other bundles.
+/// See [`KindWorld::register_kind`].
+///
+fn kind_components<T: EntityKind>(world: &World) -> Option<Vec<ComponentId>> {
+    registered_bundle_components::<KindBundle<T>>(world)
+}
+
+fn registered_bundle_components<B: Bundle>(world: &World) -> Option<Vec<ComponentId>> {
+    let bundles = world.bundles();
+    let bundle_id = bundles.get_id(TypeId::of::<B>())?;
+    Some(bundles.get(bundle_id)?.components().to_vec())
+}
+
+fn bundle_components<B: Bundle>(world: &mut World) -> Vec<ComponentId> {
+    if world.bundles().get_id(TypeId::of::<B>()).is_none() {
+        // Bundle info is only registered on first use, so register it using an empty entity
+        let mut entity = world.spawn_empty();
+        entity.remove::<B>();
+        entity.despawn();
+    }
+    registered_bundle_components::<B>(world).unwrap_or_default()
+}
+
+///
+/// Returns the [`ComponentId`] of every component required by the registered kinds of the given [`Entity`],
+/// except for the given kinds.
+///
+fn other_kind_components(
+    world: &World,
+    entity: Entity,
+    excluded: &[TypeId],
+) -> HashSet<ComponentId> {
+    let Some(registry) = world.get_resource::<KindRegistry>() else {
+        return HashSet::new();
+    };
+    let kinds: Vec<_> = registry
+        .iter()
+        .filter(|info| !excluded.contains(&info.type_id))
+        .map(|info| (info.marker_type_id, info.components))
+        .collect();
+    let mut component_ids = HashSet::new();
+    for (marker_type_id, components) in kinds {
+        let has_kind = world
+            .components()
+            .get_id(marker_type_id)
+            .zip(world.get_entity(entity))
+            .is_some_and(|(marker_id, entity)| entity.contains_id(marker_id));
+        if has_kind {
+            component_ids.extend(components(world).into_iter().flatten());
+        }
+    }
+    component_ids
+}
+
+///
+/// Inserts the components of the given [`Bundle`] for which `filter` returns true into the given [`Entity`],
+/// and drops the rest.
+///
+fn insert_filtered<B: Bundle>(
+    world: &mut World,
+    entity: Entity,
+    bundle: B,
+    filter: impl Fn(ComponentId) -> bool,
+) {
+    let components: Vec<_> = bundle_components::<B>(world)
+        .into_iter()
+        .map(|component_id| {
+            let drop = world
+                .components()
+                .get_info(component_id)
+                .and_then(|info| info.drop());
+            (component_id, filter(component_id), drop)
+        })
+        .collect();
+    let mut components = components.into_iter();
+    let mut entity = world.entity_mut(entity);
+    bundle.get_components(&mut |_, component| {
+        let Some((component_id, insert, drop)) = components.next() else {
+            return;
+        };
+        if insert {
+            // SAFE: Bundle components are given in the same order as the component ids of its bundle info
+            unsafe { entity.insert_by_id(component_id, component) };
+        } else if let Some(drop) = drop {
+            // SAFE: Component is owned by the bundle and matches its component id, and is not inserted
+            unsafe { drop(component) };
+        }
+    });
+}
+
+///
+/// Inserts the given [`KindBundle`] into the given [`Entity`], without replacing any of its existing
+/// [`EntityKind::DefaultBundle`] components.
+///
+fn insert_kind_bundle_preserving<T: EntityKind>(
+    world: &mut World,
+    entity: Entity,
+    kind_bundle: KindBundle<T>,
+) {
+    let KindBundle {
+        kind,
+        default_bundle,
+        bundle,
+        #[cfg(all(feature = "provenance", debug_assertions))]
+        provenance,
+    } = kind_bundle;
+    let default_components = bundle_components::<T::DefaultBundle>(world);
+    let entity_ref = world.entity(entity);
+    let present: HashSet<_> = default_components
+        .into_iter()
+        .filter(|component_id| entity_ref.contains_id(*component_id))
+        .collect();
+    world.entity_mut(entity).insert((kind, bundle));
+    #[cfg(all(feature = "provenance", debug_assertions))]
+    world.entity_mut(entity).insert(provenance);
+    insert_filtered(world, entity, default_bundle, |component_id| {
+        !present.contains(&component_id)
+    });
+}
+
+fn retain_kind<T: EntityKind>(entity: Entity, world: &mut World) {
+    let Some(registry) = world.get_resource::<KindRegistry>() else {
+        return;
+    };
+    let removers: Vec<_> = registry
+        .iter()
+        .filter(|info| info.type_id() != TypeId::of::<T>())
+        .map(|info| info.remove)
+        .collect();
+    let Some(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    let kind_bundle = entity.take::<KindBundle<T>>();
+    for remove in removers {
+        remove(&mut entity);
+    }
+    if let Some(kind_bundle) = kind_bundle {
+        entity.insert(kind_bundle);
+    }
+}
+
+///
+/// Extension trait used to insert a new [`EntityKind`] into any [`Entity`] using some [`EntityCommands`].
+///
+pub trait InsertKind<'w, 's, 'a> {
+    ///
+    /// Inserts a new [`EntityKind`] into the associated [`Entity`] and returns an [`EntityKindCommands`] for it.
+    ///
+    fn insert_kind<T: EntityKind>(self, bundle: T::Bundle) -> EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Inserts a new [`EntityKind`] into the associated [`Entity`] and returns an [`EntityKindCommands`] for it
+    /// without consuming the original [`EntityCommands`].
+    ///
+    fn insert_kind_in_place<T: EntityKind>(
+        &mut self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, '_, T>;
+
+    ///
+    /// Inserts a new [`EntityKind`] into the associated [`Entity`] and returns an [`EntityKindCommands`] for it,
+    /// preserving any existing [`EntityKind::DefaultBundle`] components.
+    ///
+    /// Each default component is checked separately when the commands are applied, so only the missing ones
+    /// are inserted with their default values.
+    ///
+    fn insert_kind_preserving<T: EntityKind>(
+        self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T>;
+}
+
+impl<'w, 's, 'a> InsertKind<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
+    #[track_caller]
+    fn insert_kind<T: EntityKind>(
+        mut self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T> {
+        self.insert(KindBundle::<T>::new(bundle));
+        // SAFE: `KindBundle` was just inserted
+        unsafe { EntityKindCommands::from_entity_unchecked(self) }
+    }
+
+    #[track_caller]
+    fn insert_kind_in_place<T: EntityKind>(
+        &mut self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, '_, T> {
+        let entity = self.id();
+        self.insert(KindBundle::<T>::new(bundle));
+        // SAFE: `KindBundle` was just inserted
+        unsafe { EntityKindCommands::from_entity_unchecked(self.commands().entity(entity)) }
+    }
+
+    #[track_caller]
+    fn insert_kind_preserving<T: EntityKind>(
+        mut self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T> {
+        let kind_bundle = KindBundle::<T>::new(bundle);
+        self.add(move |entity: Entity, world: &mut World| {
+            if world.get_entity(entity).is_some() {
+                insert_kind_bundle_preserving(world, entity, kind_bundle);
+            }
+        });
+        // SAFE: `KindBundle` is inserted along with this command
+        unsafe { EntityKindCommands::from_entity_unchecked(self) }
+    }
+}
+
+///
+/// A [`Component`] which records where an [`Entity`] was last given some [`EntityKind`].
+///
+/// This component is part of each [`KindBundle`] only in debug builds with the `provenance` feature enabled,
+/// so it is inserted and removed along with its kind. It usually records where the [`KindBundle`] was created, or where the kind was transferred
+/// using [`KindCommands::transfer_kind`].
+///
+#[cfg(all(feature = "provenance", debug_assertions))]
+#[derive(Component)]
+pub struct KindProvenance<T: EntityKind> {
+    location: &'static Location<'static>,
+    marker: PhantomData<T>,
+}
+
+#[cfg(all(feature = "provenance", debug_assertions))]
+impl<T: EntityKind> KindProvenance<T> {
+    fn new(location: &'static Location<'static>) -> Self {
+        Self {
+            location,
+            marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the source location where the [`EntityKind`] was inserted.
+    ///
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+///
+/// Extension trait used to reinterpret any [`EntityCommands`] as an [`EntityKindCommands`] without inserting anything.
+///
+pub trait AsKind<'w, 's, 'a> {
+    ///
+    /// Returns an [`EntityKindCommands`] for the associated [`Entity`].
+    ///
+    /// # Safety
+    ///
+    /// This function assumes the associated [`Entity`] has the given [`EntityKind`].
+    ///
+    unsafe fn as_kind<T: EntityKind>(self) -> EntityKindCommands<'w, 's, 'a, T>;
+}
+
+impl<'w, 's, 'a> AsKind<'w, 's, 'a> for EntityCommands<'w, 's, 'a> {
+    unsafe fn as_kind<T: EntityKind>(self) -> EntityKindCommands<'w, 's, 'a, T> {
+        EntityKindCommands::from_entity_unchecked(self)
+    }
+}
+
+///
+/// Extension trait which provides [`EntityKind`] support for [`Commands`].
+///
+pub trait KindCommands<'w, 's, 'a> {
+    ///
+    /// Spawns a new [`Entity`] with given [`EntityKind`] and returns an [`EntityKindCommands`] for it.
+    ///
+    fn spawn_with_kind<T: EntityKind>(self, bundle: T::Bundle)
+        -> EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Spawns a new [`Entity`] with given [`EntityKind`] and returns an [`EntityKindCommands`] for it.
+    ///
+    /// If no bundle is given, the default [`EntityKind::Bundle`] is used instead.
+    ///
+    fn spawn_with_kind_or_default<T: EntityKind>(
+        self,
+        bundle: Option<T::Bundle>,
+    ) -> EntityKindCommands<'w, 's, 'a, T>
+    where
+        T::Bundle: Default;
+
+    ///
+    /// Reserves a new [`Entity`] with given [`EntityKind`] and returns it along with an [`EntityKindCommands`] for it.
+    ///
+    /// The returned [`Entity`] is reserved immediately, so it may be sent elsewhere (such as over the network)
+    /// before the commands are applied. However, its components are only inserted once the commands are applied.
+    ///
+    fn reserve_kind<T: EntityKind>(
+        self,
+        bundle: T::Bundle,
+    ) -> (Entity, EntityKindCommands<'w, 's, 'a, T>);
+
+    ///
+    /// Spawns the given [`Entity`] with given [`EntityKind`] if it does not exist, and returns an
+    /// [`EntityKindCommands`] for it.
+    ///
+    /// If the entity already has this kind, only the given bundle is inserted.
+    ///
+    fn get_or_spawn_with_kind<T: EntityKind>(
+        self,
+        entity: Entity,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Moves the given [`EntityKind`] and its components to another [`Entity`], and returns an
+    /// [`EntityCommands`] for it.
+    ///
+    /// Component values are taken from the source entity when the commands are applied.
+    /// If any of these components is also required by another kind of the source entity registered in the
+    /// [`KindRegistry`], or if either entity does not exist, nothing is moved and a warning is logged instead.
+    /// Because of this, the returned [`EntityCommands`] is not associated with the kind. Use
+    /// [`TryWithKind`] to check the result once the commands are applied.
+    ///
+    fn transfer_kind<T: EntityKind>(self, from: &T, to: Entity) -> EntityCommands<'w, 's, 'a>;
+
+    ///
+    /// Spawns a new [`Entity`] with given [`EntityKind`], calls `f` with its [`EntityKindCommands`], and returns it.
+    ///
+    /// `f` returns the given [`EntityKindCommands`], so that fluent methods may be chained within it:
+    ///
+    /// ```ignore
+    /// commands.spawn_with_kind_and::<Agent, _>(bundle, |agent| agent.insert(Destination(target)));
+    /// ```
+    ///
+    fn spawn_with_kind_and<T, F>(self, bundle: T::Bundle, f: F) -> Entity
+    where
+        T: EntityKind,
+        F: for<'x> FnOnce(
+            &'x mut EntityKindCommands<'w, 's, 'a, T>,
+        ) -> &'x mut EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Spawns a new [`Entity`] with a tuple of [`EntityKind`] types and returns it.
+    ///
+    fn spawn_with_kinds<K: EntityKinds>(self, bundles: K::Bundles) -> Entity;
+
+    ///
+    /// Returns a new [`EntityKindCommands`] for some [`EntityKind`].
+    ///
+    fn with_kind<T: EntityKind>(self, kind: &T) -> EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Returns a new [`EntityKindCommands`] for some [`Entity`] which is known to have the given [`EntityKind`].
+    ///
+    /// # Safety
+    ///
+    /// This function assumes `entity` is associated with the correct [`EntityKind`].
+    ///
+    unsafe fn reacquire_kind<T: EntityKind>(
+        self,
+        entity: Entity,
+    ) -> EntityKindCommands<'w, 's, 'a, T>;
+
+    ///
+    /// Returns a new [`EntityKindCommands`] for some [`Entity`] if it has the given [`EntityKind`] in the given query.
+    ///
+    fn entity_with_kind<T: EntityKind>(
+        self,
+        query: &Query<(), WithKind<T>>,
+        entity: Entity,
+    ) -> Option<EntityKindCommands<'w, 's, 'a, T>>;
+}
+
+impl<'w, 's, 'a> KindCommands<'w, 's, 'a> for &'a mut Commands<'w, 's> {
+    #[track_caller]
+    fn spawn_with_kind<T: EntityKind>(
+        self,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T> {
+        self.spawn_empty().insert_kind(bundle)
+    }
+
+    #[track_caller]
+    fn spawn_with_kind_or_default<T: EntityKind>(
+        self,
+        bundle: Option<T::Bundle>,
+    ) -> EntityKindCommands<'w, 's, 'a, T>
+    where
+        T::Bundle: Default,
+    {
+        self.spawn_with_kind(bundle.unwrap_or_default())
+    }
+
+    #[track_caller]
+    fn reserve_kind<T: EntityKind>(
+        self,
+        bundle: T::Bundle,
+    ) -> (Entity, EntityKindCommands<'w, 's, 'a, T>) {
+        let kind = self.spawn_with_kind(bundle);
+        (kind.entity(), kind)
+    }
+
+    #[track_caller]
+    fn get_or_spawn_with_kind<T: EntityKind>(
+        self,
+        entity: Entity,
+        bundle: T::Bundle,
+    ) -> EntityKindCommands<'w, 's, 'a, T> {
+        let kind_bundle = KindBundle::<T>::new(bundle);
+        let mut entity = self.get_or_spawn(entity);
+        entity.add(move |entity: Entity, world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if entity.contains::<Kind<T>>() {
+                entity.insert(kind_bundle.bundle);
+            } else {
+                entity.insert(kind_bundle);
+            }
+        });
+        // SAFE: `KindBundle` is inserted along with this command, if not already present
+        unsafe { EntityKindCommands::from_entity_unchecked(entity) }
+    }
+
+    #[track_caller]
+    fn transfer_kind<T: EntityKind>(self, from: &T, to: Entity) -> EntityCommands<'w, 's, 'a> {
+        #[cfg(all(feature = "provenance", debug_assertions))]
+        let location = Location::caller();
+        let from = from.entity();
+        let mut entity = self.entity(to);
+        entity.add(move |to: Entity, world: &mut World| {
+            if world.get_entity(to).is_none() {
+                warn!(
+                    "cannot transfer kind `{}` from {from:?} to {to:?}, because {to:?} does not exist",
+                    kind_name::<T>()
+                );
+                return;
+            }
+            let required = other_kind_components(world, from, &[TypeId::of::<T>()]);
+            let shared = bundle_components::<KindBundle<T>>(world)
+                .into_iter()
+                .find(|component_id| required.contains(component_id));
+            if let Some(component_id) = shared {
+                warn!(
+                    "cannot transfer kind `{}` from {from:?} to {to:?}, because component `{}` is required by another kind of {from:?}",
+                    kind_name::<T>(),
+                    world
+                        .components()
+                        .get_info(component_id)
+                        .map_or("<unknown>", |info| info.name()),
+                );
+                return;
+            }
+            let Some(kind_bundle) = world
+                .get_entity_mut(from)
+                .and_then(|mut from| from.take::<KindBundle<T>>())
+            else {
+                warn!(
+                    "cannot transfer kind `{}` from {from:?} to {to:?}, because {from:?} does not have all of its components",
+                    kind_name::<T>()
+                );
+                return;
+            };
+            #[cfg(all(feature = "provenance", debug_assertions))]
+            let kind_bundle = KindBundle {
+                provenance: KindProvenance::new(location),
+                ..kind_bundle
+            };
+            world.entity_mut(to).insert(kind_bundle);
+        });
+        entity
+    }
+
+    #[track_caller]
+    fn spawn_with_kind_and<T, F>(self, bundle: T::Bundle, f: F) -> Entity
+    where
+        T: EntityKind,
+        F: for<'x> FnOnce(
+            &'x mut EntityKindCommands<'w, 's, 'a, T>,
+        ) -> &'x mut EntityKindCommands<'w, 's, 'a, T>,
+    {
+        let mut kind = self.spawn_with_kind(bundle);
+        f(&mut kind);
+        kind.entity()
+    }
+
+    #[track_caller]
+    fn spawn_with_kinds<K: EntityKinds>(self, bundles: K::Bundles) -> Entity {
+        self.spawn(K::kind_bundles(bundles)).id()
+    }
+
+    fn with_kind<T: EntityKind>(self, kind: &T) -> EntityKindCommands<'w, 's, 'a, T> {
+        // SAFE: `kind` may only reference an entity with correct kind
+        unsafe { EntityKindCommands::from_entity_unchecked(self.entity(kind.entity())) }
+    }
+
+    unsafe fn reacquire_kind<T: EntityKind>(
+        self,
+        entity: Entity,
+    ) -> EntityKindCommands<'w, 's, 'a, T> {
+        EntityKindCommands::from_entity_unchecked(self.entity(entity))
+    }
+
+    fn entity_with_kind<T: EntityKind>(
+        self,
+        query: &Query<(), WithKind<T>>,
+        entity: Entity,
+    ) -> Option<EntityKindCommands<'w, 's, 'a, T>> {
+        query
+            .contains(entity)
+            // SAFE: Entity kind was just checked
+            .then(|| unsafe { EntityKindCommands::from_entity_unchecked(self.entity(entity)) })
+    }
+}
+
+///
+/// A tuple of [`EntityKind`] types which may be spawned together using [`KindCommands::spawn_with_kinds`].
+///
+/// Note that the kinds must not share any components, as they are inserted as a single [`Bundle`].
+///
+pub trait EntityKinds: 'static {
+    ///
+    /// A tuple of [`EntityKind::Bundle`] for each [`EntityKind`] in this tuple.
+    ///
+    type Bundles;
+
+    ///
+    /// A tuple of [`KindBundle`] for each [`EntityKind`] in this tuple.
+    ///
+    type KindBundles: Bundle;
+
+    ///
+    /// Creates a [`KindBundle`] for each [`EntityKind`] in this tuple using the given bundles.
+    ///
+    fn kind_bundles(bundles: Self::Bundles) -> Self::KindBundles;
+}
+
+macro_rules! impl_entity_kinds {
+    ($($kind:ident => $bundle:ident),*) => {
+        impl<$($kind: EntityKind),*> EntityKinds for ($($kind,)*) {
+            type Bundles = ($($kind::Bundle,)*);
+            type KindBundles = ($(KindBundle<$kind>,)*);
+
+            #[track_caller]
+            fn kind_bundles(($($bundle,)*): Self::Bundles) -> Self::KindBundles {
+                ($(KindBundle::new($bundle),)*)
+            }
+        }
+    };
+}
+
+impl_entity_kinds!(A => a, B => b);
+impl_entity_kinds!(A => a, B => b, C => c);
+impl_entity_kinds!(A => a, B => b, C => c, D => d);
+
+///
+/// Extension trait used to safely cast an [`Entity`] into an [`EntityKind`].
+///
+pub trait TryWithKind {
+    ///
+    /// Checks if this [`Entity`] has the given [`EntityKind`] and returns it.
+    ///
+    fn try_with_kind<T: EntityKind>(self) -> Option<T>;
+
+    ///
+    /// Checks if this [`Entity`] has the given [`EntityKind`] and returns it, or a [`KindError`] if it does not.
+    ///
+    fn with_kind_result<T: EntityKind>(self) -> Result<T, KindError>;
+}
+
+impl TryWithKind for &EntityRef<'_> {
+    fn try_with_kind<T: EntityKind>(self) -> Option<T> {
+        self.contains::<Kind<T>>()
+            // SAFE: Entity kind was just checked
+            .then(|| unsafe { T::from_entity_unchecked(self.id()) })
+    }
+
+    fn with_kind_result<T: EntityKind>(self) -> Result<T, KindError> {
+        self.try_with_kind().ok_or(KindError::WrongKind {
+            entity: self.id(),
+            expected: kind_name::<T>(),
+        })
+    }
+}
+
+///
+/// Extension trait which provides [`EntityKind`] support for [`World`].
+///
+pub trait KindWorld {
+    ///
+    /// Registers the given [`EntityKind`] in the [`KindRegistry`] of this [`World`], inserting it if needed,
+    /// along with the [`KindBundle`] of the kind.
+    ///
+    /// Bevy only registers bundles which are inserted directly, so this is required for kinds which are
+    /// only ever inserted as part of other bundles (see `examples/multiple.rs`) to be checked by
+    /// [`check_invariants`]. This is done by [`KindPlugin`].
+    ///
+    fn register_kind<T: EntityKind>(&mut self) -> &mut Self;
+
+    ///
+    /// Returns a new [`EntityKindWorldMut`] for some [`EntityKind`],
+    /// or [`None`] if the entity no longer exists or no longer has this kind.
+    ///
+    fn with_kind<T: EntityKind>(&mut self, kind: &T) -> Option<EntityKindWorldMut<'_, T>>;
+
+    ///
+    /// Calls `f` with mutable access to the entity of the given [`EntityKind`] and returns its result,
+    /// or returns [`None`] if the entity no longer exists or no longer has this kind.
+    ///
+    /// Same as [`KindWorld::with_kind`], for use with functions which expect an [`EntityMut`].
+    ///
+    fn with_kind_mut<T: EntityKind, F: FnOnce(&mut EntityMut) -> R, R>(
+        &mut self,
+        kind: &T,
+        f: F,
+    ) -> Option<R>;
+
+    ///
+    /// Checks the invariants of all entities of every [`EntityKind`] registered in the [`KindRegistry`],
+    /// and returns a [`KindValidationReport`] of all violations.
+    ///
+    /// Unlike [`KindValidation::Panic`], this does not stop at the first invalid entity.
+    ///
+    fn validate_all_kinds(&mut self) -> KindValidationReport;
+}
+
+impl KindWorld for World {
+    fn register_kind<T: EntityKind>(&mut self) -> &mut Self {
+        self.init_resource::<KindRegistry>();
+        self.resource_mut::<KindRegistry>().register::<T>();
+        bundle_components::<KindBundle<T>>(self);
+        self
+    }
+
+    fn with_kind<T: EntityKind>(&mut self, kind: &T) -> Option<EntityKindWorldMut<'_, T>> {
+        let entity = self.get_entity_mut(kind.entity())?;
+        entity
+            .contains::<Kind<T>>()
+            .then(|| EntityKindWorldMut(entity, PhantomData))
+    }
+
+    fn with_kind_mut<T: EntityKind, F: FnOnce(&mut EntityMut) -> R, R>(
+        &mut self,
+        kind: &T,
+        f: F,
+    ) -> Option<R> {
+        let mut entity = self.with_kind(kind)?;
+        Some(f(entity.as_entity_mut()))
+    }
+
+    fn validate_all_kinds(&mut self) -> KindValidationReport {
+        let validators: Vec<_> = self
+            .get_resource::<KindRegistry>()
+            .into_iter()
+            .flat_map(|registry| registry.iter())
+            .map(|info| (info.name, info.validate))
+            .collect();
+        let mut report = KindValidationReport::default();
+        for (name, validate) in validators {
+            let errors = validate(self);
+            report.counts.push((name, errors.len()));
+            report.errors.extend(errors);
+        }
+        report
+    }
+}
+
+///
+/// A report of all entities which violate the invariants of their [`EntityKind`].
+///
+/// See [`KindWorld::validate_all_kinds`].
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KindValidationReport {
+    errors: Vec<KindError>,
+    counts: Vec<(&'static str, usize)>,
+}
+
+impl KindValidationReport {
+    ///
+    /// Returns true if no violations were found.
+    ///
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    ///
+    /// Returns all violations found, as [`KindError`]s.
+    ///
+    pub fn errors(&self) -> &[KindError] {
+        &self.errors
+    }
+
+    ///
+    /// Returns the number of invalid entities of the given [`EntityKind`].
+    ///
+    pub fn count<T: EntityKind>(&self) -> usize {
+        self.counts
+            .iter()
+            .find(|(name, _)| *name == kind_name::<T>())
+            .map_or(0, |(_, count)| *count)
+    }
+
+    ///
+    /// Iterates over the name of each validated [`EntityKind`] and its number of invalid entities.
+    ///
+    pub fn counts(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.counts.iter().copied()
+    }
+}
+
+///
+/// A wrapper for [`EntityMut`] to access entities with a specific [`EntityKind`] immediately.
+///
+pub struct EntityKindWorldMut<'w, T: EntityKind>(EntityMut<'w>, PhantomData<T>);
+
+impl<'w, T: EntityKind> EntityKindWorldMut<'w, T> {
+    ///
+    /// Returns the associated [`Entity`].
+    ///
+    pub fn entity(&self) -> Entity {
+        self.0.id()
+    }
+
+    ///
+    /// Returns the associated [`EntityKind`].
+    ///
+    pub fn get(&self) -> T {
+        // SAFE: `EntityKindWorldMut<T>` is always associated with an entity of matching kind
+        unsafe { T::from_entity_unchecked(self.entity()) }
+    }
+
+    ///
+    /// Returns the underlying [`EntityMut`].
+    ///
+    pub fn as_entity_mut(&mut self) -> &mut EntityMut<'w> {
+        &mut self.0
+    }
+
+    ///
+    /// Returns a [`Component`] of the associated [`Entity`], if it exists.
+    ///
+    pub fn get_component<C: Component>(&self) -> Option<&C> {
+        self.0.get::<C>()
+    }
+
+    ///
+    /// Returns a [`Component`] of the associated [`Entity`] as mutable, if it exists.
+    ///
+    pub fn get_component_mut<C: Component>(&mut self) -> Option<Mut<C>> {
+        self.0.get_mut::<C>()
+    }
+
+    pub fn insert(&mut self, component: impl Component) -> &mut Self {
+        self.0.insert(component);
+        self
+    }
+}
+
+///
+/// An error returned when an [`Entity`] cannot be used as some [`EntityKind`].
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KindError {
+    ///
+    /// The entity does not have the expected [`EntityKind`].
+    ///
+    WrongKind {
+        entity: Entity,
+        expected: &'static str,
+    },
+
+    ///
+    /// The entity does not exist.
+    ///
+    EntityNotFound(Entity),
+
+    ///
+    /// The entity has the expected [`EntityKind`], but is missing one of its components.
+    ///
+    MissingComponent {
+        entity: Entity,
+        kind: &'static str,
+        component: String,
+    },
+
+    ///
+    /// The components of the [`EntityKind`] are unknown, because its [`KindBundle`] is not registered.
+    ///
+    /// See [`KindWorld::register_kind`].
+    ///
+    NotRegistered { kind: &'static str },
+}
+
+impl Display for KindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatResult {
+        match self {
+            Self::WrongKind { entity, expected } => {
+                write!(f, "{entity:?} is not of kind `{expected}`")
+            }
+            Self::EntityNotFound(entity) => write!(f, "{entity:?} does not exist"),
+            Self::MissingComponent {
+                entity,
+                kind,
+                component,
+            } => write!(
+                f,
+                "{entity:?} of kind `{kind}` is missing component `{component}`"
+            ),
+            Self::NotRegistered { kind } => write!(f, "kind `{kind}` is not registered"),
+        }
+    }
+}
+
+impl Error for KindError {}
+
+///
+/// Returns a human-readable name of the given [`EntityKind`], intended for logging and diagnostics.
+///
+pub fn kind_name<T: EntityKind>() -> &'static str {
+    type_name::<T>()
+}
+
+///
+/// Remaps the [`Entity`] of the given [`EntityKind`] using an [`EntityMapper`].
+///
+/// This is useful when implementing [`MapEntities`](bevy_ecs::entity::MapEntities) for components which reference other entities by kind,
+/// so that the references remain valid after a scene is spawned.
+///
+pub fn map_kind<T: EntityKind>(kind: &mut T, entity_mapper: &mut EntityMapper) {
+    let entity = entity_mapper.get_or_reserve(kind.entity());
+    // SAFE: Mapped entity refers to the same entity, which is assumed to have the same kind
+    *kind = unsafe { T::from_entity_unchecked(entity) };
+}
+
+///
+/// Asserts that the given [`Entity`] has the given [`EntityKind`] and all of its components.
+///
+/// # Panics
+///
+/// Panics with a message naming the first missing component, if any.
+///
+pub fn assert_invariants<T: EntityKind>(world: &World, entity: Entity) {
+    if let Err(error) = check_invariants::<T>(world, entity) {
+        panic!("{error}");
+    }
+}
+
+///
+/// Checks that the given [`Entity`] has the given [`EntityKind`] and all of its components.
+///
+/// Returns a [`KindError`] describing the first violation, if any.
+///
+/// If the [`KindBundle`] of the kind has never been inserted directly, its components are unknown and
+/// [`KindError::NotRegistered`] is returned. See [`KindWorld::register_kind`].
+///
+pub fn check_invariants<T: EntityKind>(world: &World, entity: Entity) -> Result<(), KindError> {
+    let component_ids = kind_components::<T>(world);
+    check_components::<T>(world, entity, component_ids.as_deref())
+}
+
+fn check_components<T: EntityKind>(
+    world: &World,
+    entity: Entity,
+    component_ids: Option<&[ComponentId]>,
+) -> Result<(), KindError> {
+    let entity_ref = world
+        .get_entity(entity)
+        .ok_or(KindError::EntityNotFound(entity))?;
+    if !entity_ref.contains::<Kind<T>>() {
+        return Err(KindError::WrongKind {
+            entity,
+            expected: kind_name::<T>(),
+        });
+    }
+    let component_ids = component_ids.ok_or(KindError::NotRegistered {
+        kind: kind_name::<T>(),
+    })?;
+    match find_missing_component(world, entity, component_ids) {
+        Some(component) => Err(KindError::MissingComponent {
+            entity,
+            kind: kind_name::<T>(),
+            component: component.to_owned(),
+        }),
+        None => Ok(()),
+    }
+}
+
+///
+/// Asserts that an [`Entity`] has some [`EntityKind`] and all of its components.
+///
+/// The world is passed as given, so callers pass a `&World` (or anything which derefs to one).
+/// An optional message may be given, similar to [`assert!`]:
+///
+/// ```ignore
+/// kindly_assert!(&world, entity, Agent);
+/// kindly_assert!(&world, entity, Agent, "agent {} is invalid", name);
+/// ```
+///
+#[macro_export]
+macro_rules! kindly_assert {
+    ($world:expr, $entity:expr, $kind:ty $(,)?) => {
+        $crate::assert_invariants::<$kind>($world, $entity)
+    };
+    ($world:expr, $entity:expr, $kind:ty, $($arg:tt)+) => {
+        if let Err(error) = $crate::check_invariants::<$kind>($world, $entity) {
+            panic!("{}: {}", format_args!($($arg)+), error);
+        }
+    };
+}
+
+///
+/// Iterates over the given entities which have the given [`EntityKind`] in the given [`World`].
+///
+pub fn filter_kind<'w, T: EntityKind, I>(
+    world: &'w World,
+    entities: I,
+) -> impl Iterator<Item = T> + 'w
+where
+    I: IntoIterator<Item = Entity>,
+    I::IntoIter: 'w,
+{
+    entities
+        .into_iter()
+        .filter_map(move |entity| world.get_entity(entity)?.try_with_kind())
+}
+
+///
+/// Walks up the hierarchy of the given [`Entity`] and returns its closest ancestor with the given [`EntityKind`].
+///
+#[cfg(feature = "hierarchy")]
+pub fn parent_of_kind<T: EntityKind>(world: &World, entity: Entity) -> Option<T> {
+    let mut current = world.get::<Parent>(entity)?.get();
+    loop {
+        let entity_ref = world.get_entity(current)?;
+        if let Some(kind) = entity_ref.try_with_kind() {
+            return Some(kind);
+        }
+        current = entity_ref.get::<Parent>()?.get();
+    }
+}
+
+///
+/// Iterates over the direct children of the given [`Entity`] with the given [`EntityKind`].
+///
+#[cfg(feature = "hierarchy")]
+pub fn children_of_kind<T: EntityKind>(
+    world: &World,
+    entity: Entity,
+) -> impl Iterator<Item = T> + '_ {
+    world
+        .get::<Children>(entity)
+        .into_iter()
+        .flat_map(|children| children.iter())
+        .filter_map(move |child| world.get_entity(*child)?.try_with_kind())
+}
+
+///
+/// Returns the names of all registered [`EntityKind`] types which the given [`Entity`] has, as given by [`kind_name`].
+///
+/// Returns an empty list if the entity does not exist or no [`KindRegistry`] is present.
+///
+pub fn kinds_of(world: &World, entity: Entity) -> Vec<&'static str> {
+    let (Some(registry), Some(entity)) = (
+        world.get_resource::<KindRegistry>(),
+        world.get_entity(entity),
+    ) else {
+        return Vec::new();
+    };
+    registry
+        .iter()
+        .filter(|info| {
+            info.marker_component_id(world)
+                .is_some_and(|component_id| entity.contains_id(component_id))
+        })
+        .map(|info| info.name)
+        .collect()
+}
+
+///
+/// Returns a new [`QueryState`] for all entities with the given [`EntityKind`].
+///
+pub fn kind_query_state<T: EntityKind>(world: &mut World) -> QueryState<EntityWithKind<T>> {
+    world.query::<EntityWithKind<T>>()
+}
+
+///
+/// Returns the first entity with the given [`EntityKind`] which matches the given predicate.
+///
+pub fn find_kind<T: EntityKind, F: FnMut(&T, &World) -> bool>(
+    world: &mut World,
+    mut predicate: F,
+) -> Option<T> {
+    let mut query = kind_query_state::<T>(world);
+    let world: &World = world;
+    query
+        .iter(world)
+        .map(|item| item.get())
+        .find(|kind| predicate(kind, world))
+}
+
+///
+/// Returns a run condition which is true if any entity with the given [`EntityKind`] exists.
+///
+/// Similar to [`any_with_component`](bevy_ecs::schedule::common_conditions::any_with_component) in usage.
+///
+pub fn any_with_kind<T: EntityKind>() -> impl FnMut(Query<(), WithKind<T>>) -> bool + Clone {
+    move |query: Query<(), WithKind<T>>| !query.is_empty()
+}
+
+///
+/// Returns a run condition which is true if no entities with the given [`EntityKind`] exist.
+///
+pub fn no_entities_with_kind<T: EntityKind>() -> impl FnMut(Query<(), WithKind<T>>) -> bool + Clone
+{
+    move |query: Query<(), WithKind<T>>| query.is_empty()
+}
+
+///
+/// A [`Resource`] which keeps track of registered [`EntityKind`] types.
+///
+#[derive(Resource, Default)]
+pub struct KindRegistry {
+    kinds: Vec<KindInfo>,
+}
+
+impl KindRegistry {
+    ///
+    /// Registers the given [`EntityKind`], if it is not already registered.
+    ///
+    /// Prefer [`KindWorld::register_kind`], which also registers the [`KindBundle`] of the kind.
+    ///
+    pub fn register<T: EntityKind>(&mut self) -> &mut Self {
+        if !self.contains::<T>() {
+            self.kinds.push(KindInfo::new::<T>());
+        }
+        self
+    }
+
+    ///
+    /// Returns true if the given [`EntityKind`] is registered.
+    ///
+    pub fn contains<T: EntityKind>(&self) -> bool {
+        self.get(TypeId::of::<T>()).is_some()
+    }
+
+    ///
+    /// Returns the [`KindInfo`] of a registered [`EntityKind`] by its [`TypeId`].
+    ///
+    pub fn get(&self, type_id: TypeId) -> Option<&KindInfo> {
+        self.kinds.iter().find(|info| info.type_id == type_id)
+    }
+
+    ///
+    /// Returns the [`KindInfo`] of a registered [`EntityKind`] by its name, as given by [`kind_name`].
+    ///
+    pub fn get_by_name(&self, name: &str) -> Option<&KindInfo> {
+        self.kinds.iter().find(|info| info.name == name)
+    }
+
+    ///
+    /// Iterates over all registered [`EntityKind`] types.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &KindInfo> {
+        self.kinds.iter()
+    }
+
+    ///
+    /// Iterates over all registered [`EntityKind`] types in the given category, as given by [`KindMetadata::category`].
+    ///
+    pub fn iter_category<'a>(&'a self, category: &'a str) -> impl Iterator<Item = &'a KindInfo> {
+        self.kinds
+            .iter()
+            .filter(move |info| info.metadata.category == Some(category))
+    }
+}
+
+///
+/// Information about an [`EntityKind`] registered in the [`KindRegistry`].
+///
+pub struct KindInfo {
+    type_id: TypeId,
+    marker_type_id: TypeId,
+    name: &'static str,
+    metadata: KindMetadata,
+    remove: fn(&mut EntityMut),
+    validate: fn(&mut World) -> Vec<KindError>,
+    components: fn(&World) -> Option<Vec<ComponentId>>,
+}
+
+impl KindInfo {
+    fn new<T: EntityKind>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            marker_type_id: TypeId::of::<Kind<T>>(),
+            name: kind_name::<T>(),
+            metadata: T::METADATA,
+            remove: remove_kind_bundle::<T>,
+            validate: validate_all::<T>,
+            components: kind_components::<T>,
+        }
+    }
+
+    ///
+    /// Returns the [`TypeId`] of the registered [`EntityKind`].
+    ///
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    ///
+    /// Returns the name of the registered [`EntityKind`].
+    ///
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    ///
+    /// Returns the [`KindMetadata`] of the registered [`EntityKind`].
+    ///
+    pub fn metadata(&self) -> &KindMetadata {
+        &self.metadata
+    }
+
+    ///
+    /// Returns the [`ComponentId`] of the [`Kind`] marker of the registered [`EntityKind`] in the given [`World`],
+    /// or [`None`] if no entity has been given this kind in the world yet.
+    ///
+    pub fn marker_component_id(&self, world: &World) -> Option<ComponentId> {
+        world.components().get_id(self.marker_type_id)
+    }
+}
+
+///
+/// A runtime filter for entities with some registered [`EntityKind`], chosen by its [`TypeId`].
+///
+/// Similar to [`WithKind`], but useful when the kind is not known at compile time.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynKindFilter {
+    component_id: ComponentId,
+}
+
+impl DynKindFilter {
+    ///
+    /// Creates a new [`DynKindFilter`] for the [`EntityKind`] with the given [`TypeId`].
+    ///
+    /// Returns [`None`] if the kind is not registered in the [`KindRegistry`], or if no entity has been
+    /// given this kind in the given [`World`] yet.
+    ///
+    pub fn new(world: &World, type_id: TypeId) -> Option<Self> {
+        let registry = world.get_resource::<KindRegistry>()?;
+        let component_id = registry.get(type_id)?.marker_component_id(world)?;
+        Some(Self { component_id })
+    }
+
+    ///
+    /// Returns true if the given entity has the [`EntityKind`] of this filter.
+    ///
+    pub fn matches(&self, entity: &EntityRef) -> bool {
+        entity.contains_id(self.component_id)
+    }
+}
+
+///
+/// A runtime filter for entities with the given [`EntityKind`] and no other registered kind.
+///
+/// This is useful to find entities which were never given any other kind, such as an `Agent` which is not a `Person`.
+/// Static filters can't express this, since the set of all kinds is only known at runtime through the [`KindRegistry`].
+///
+/// Note that kinds which have never been given to any entity when this filter is created are ignored.
+///
+pub struct OnlyKind<T: EntityKind> {
+    component_id: ComponentId,
+    other_component_ids: Vec<ComponentId>,
+    marker: PhantomData<T>,
+}
+
+impl<T: EntityKind> OnlyKind<T> {
+    ///
+    /// Creates a new [`OnlyKind`] filter for the given [`EntityKind`] in the given [`World`].
+    ///
+    /// Returns [`None`] if no entity has been given this kind in the world yet.
+    ///
+    pub fn new(world: &World) -> Option<Self> {
+        let component_id = world.components().get_id(TypeId::of::<Kind<T>>())?;
+        let other_component_ids = world
+            .get_resource::<KindRegistry>()
+            .into_iter()
+            .flat_map(|registry| registry.iter())
+            .filter(|info| info.type_id != TypeId::of::<T>())
+            .filter_map(|info| info.marker_component_id(world))
+            .collect();
+        Some(Self {
+            component_id,
+            other_component_ids,
+            marker: PhantomData,
+        })
+    }
+
+    ///
+    /// Returns true if the given entity has this [`EntityKind`] and no other registered kind.
+    ///
+    pub fn matches(&self, entity: &EntityRef) -> bool {
+        entity.contains_id(self.component_id)
+            && !self
+                .other_component_ids
+                .iter()
+                .any(|&component_id| entity.contains_id(component_id))
+    }
+
+    ///
+    /// Returns the [`EntityKind`] of the given entity, if it matches this filter.
+    ///
+    pub fn get(&self, entity: &EntityRef) -> Option<T> {
+        // SAFE: Entity kind was just checked
+        self.matches(entity)
+            .then(|| unsafe { T::from_entity_unchecked(entity.id()) })
+    }
+}
+
+///
+/// A runtime filter for entities with any registered [`EntityKind`] in some category, as given by [`KindMetadata::category`].
+///
+/// Note that kinds which have never been given to any entity when this filter is created are ignored.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InCategory {
+    component_ids: Vec<ComponentId>,
+}
+
+impl InCategory {
+    ///
+    /// Creates a new [`InCategory`] filter for the given category in the given [`World`].
+    ///
+    pub fn new(world: &World, category: &str) -> Self {
+        let component_ids = world
+            .get_resource::<KindRegistry>()
+            .into_iter()
+            .flat_map(|registry| registry.iter_category(category))
+            .filter_map(|info| info.marker_component_id(world))
+            .collect();
+        Self { component_ids }
+    }
+
+    ///
+    /// Returns true if the given entity has any [`EntityKind`] in the category of this filter.
+    ///
+    pub fn matches(&self, entity: &EntityRef) -> bool {
+        self.component_ids
+            .iter()
+            .any(|&component_id| entity.contains_id(component_id))
+    }
+}
+
+fn validate_all<T: EntityKind>(world: &mut World) -> Vec<KindError> {
+    let mut query = world.query_filtered::<Entity, WithKind<T>>();
+    let entities: Vec<_> = query.iter(world).collect();
+    validate_entities::<T>(world, entities)
+        .into_iter()
+        .map(|(_, error)| error)
+        .collect()
+}
+
+///
+/// Checks the invariants of each of the given entities with the given [`EntityKind`],
+/// and returns those which are invalid along with their [`KindError`].
+///
+fn validate_entities<T: EntityKind>(
+    world: &World,
+    entities: impl IntoIterator<Item = Entity>,
+) -> Vec<(Entity, KindError)> {
+    let component_ids = kind_components::<T>(world);
+    entities
+        .into_iter()
+        .filter_map(|entity| {
+            check_components::<T>(world, entity, component_ids.as_deref())
+                .err()
+                .map(|error| (entity, error))
+        })
+        .collect()
+}
+
+fn remove_kind_bundle<T: EntityKind>(entity: &mut EntityMut) {
+    if entity.contains::<Kind<T>>() {
+        entity.remove::<KindBundle<T>>();
+    }
+}
+
+///
+/// A [`Plugin`] which enables all features of the given [`EntityKind`] in an [`App`].
+///
+/// By default, this plugin only registers the kind in the [`KindRegistry`].
+/// Validation and events may be enabled using [`KindPlugin::with_validation`] and [`KindPlugin::with_events`].
+///
+#[cfg(feature = "app")]
+pub struct KindPlugin<T: EntityKind> {
+    validation: Option<KindValidation>,
+    events: bool,
+    kind_set: bool,
+    marker: PhantomData<T>,
+}
+
+#[cfg(feature = "app")]
+impl<T: EntityKind> KindPlugin<T> {
+    ///
+    /// Enables validation of all entities with this [`EntityKind`] using the given [`KindValidation`] mode.
+    ///
+    pub fn with_validation(mut self, mode: KindValidation) -> Self {
+        self.validation = Some(mode);
+        self
+    }
+
+    ///
+    /// Enables [`KindInserted`] events for this [`EntityKind`].
+    ///
+    pub fn with_events(mut self) -> Self {
+        self.events = true;
+        self
+    }
+
+    ///
+    /// Enables tracking of this [`EntityKind`] in the [`KindSet`] of each entity.
+    ///
+    pub fn with_kind_set(mut self) -> Self {
+        self.kind_set = true;
+        self
+    }
+}
+
+#[cfg(feature = "app")]
+impl<T: EntityKind> Default for KindPlugin<T> {
+    fn default() -> Self {
+        Self {
+            validation: None,
+            events: false,
+            kind_set: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "app")]
+impl<T: EntityKind> Plugin for KindPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.world.register_kind::<T>();
+
+        app.configure_sets(
+            PostUpdate,
+            (
+                KindSystems::Validate,
+                KindSystems::Events,
+                KindSystems::KindSet,
+            )
+                .chain(),
+        );
+
+        if let Some(mode) = self.validation {
+            app.add_systems(
+                PostUpdate,
+                validate_kind::<T>(mode).in_set(KindSystems::Validate),
+            );
+        }
+
+        if self.events {
+            app.add_event::<KindInserted<T>>().add_systems(
+                PostUpdate,
+                send_kind_inserted::<T>.in_set(KindSystems::Events),
+            );
+        }
+
+        if self.kind_set {
+            app.add_systems(
+                PostUpdate,
+                update_kind_set::<T>.in_set(KindSystems::KindSet),
+            );
+        }
+    }
+}
+
+///
+/// A [`SystemSet`] for systems added by [`KindPlugin`], which run in [`PostUpdate`] in the given order.
+///
+#[cfg(feature = "app")]
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KindSystems {
+    ///
+    /// Systems which validate entities, enabled by [`KindPlugin::with_validation`].
+    ///
+    Validate,
+
+    ///
+    /// Systems which send [`KindInserted`] events, enabled by [`KindPlugin::with_events`].
+    ///
+    Events,
+
+    ///
+    /// Systems which update [`KindSet`] components, enabled by [`KindPlugin::with_kind_set`].
+    ///
+    KindSet,
+}
+
+///
+/// Defines how [`KindPlugin`] reacts to entities which do not have all the components of their [`EntityKind`].
+///
+#[cfg(feature = "app")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindValidation {
+    ///
+    /// Log a warning for each invalid entity, once each time it becomes invalid.
+    ///
+    Warn,
+
+    ///
+    /// Panic on the first invalid entity.
+    ///
+    Panic,
+}
+
+#[cfg(feature = "app")]
+fn validate_kind<T: EntityKind>(
+    mode: KindValidation,
+) -> impl FnMut(&World, Query<Entity, WithKind<T>>, Local<HashSet<Entity>>) {
+    move |world: &World, query: Query<Entity, WithKind<T>>, mut invalid: Local<HashSet<Entity>>| {
+        let mut current = HashSet::new();
+        for (entity, error) in validate_entities::<T>(world, &query) {
+            match mode {
+                // Only report entities which became invalid since the last run
+                KindValidation::Warn if !invalid.contains(&entity) => warn!("{error}"),
+                KindValidation::Warn => {}
+                KindValidation::Panic => panic!("{error}"),
+            }
+            current.insert(entity);
+        }
+        *invalid = current;
+    }
+}
+
+///
+/// An [`Event`] sent when an [`Entity`] is given some [`EntityKind`].
+///
+/// This event is only sent if enabled using [`KindPlugin::with_events`].
+///
+#[cfg(feature = "app")]
+#[derive(Event)]
+pub struct KindInserted<T: EntityKind> {
+    ///
+    /// The [`EntityKind`] which was inserted.
+    ///
+    pub kind: T,
+}
+
+#[cfg(feature = "app")]
+fn send_kind_inserted<T: EntityKind>(
+    query: Query<EntityWithKind<T>, AddedKind<T>>,
+    mut events: EventWriter<KindInserted<T>>,
+) {
+    for item in &query {
+        events.send(KindInserted { kind: item.get() });
+    }
+}
+
+///
+/// A [`Component`] which stores a collection of entities with some given [`EntityKind`].
+///
+#[derive(Component)]
+pub struct KindVec<T: EntityKind>(Vec<T>);
+
+impl<T: EntityKind> KindVec<T> {
+    ///
+    /// Adds the given [`EntityKind`] to this [`KindVec`].
+    ///
+    pub fn push(&mut self, kind: T) {
+        self.0.push(kind);
+    }
+
+    ///
+    /// Removes the given [`EntityKind`] from this [`KindVec`] and returns it, if it exists.
+    ///
+    pub fn remove(&mut self, kind: &T) -> Option<T> {
+        let index = self.0.iter().position(|k| k.entity() == kind.entity())?;
+        Some(self.0.remove(index))
+    }
+
+    ///
+    /// Returns true if this [`KindVec`] contains the given [`EntityKind`].
+    ///
+    pub fn contains(&self, kind: &T) -> bool {
+        self.0.iter().any(|k| k.entity() == kind.entity())
+    }
+}
+
+impl<T: EntityKind> Default for KindVec<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T: EntityKind> Deref for KindVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: EntityKind> Extend<T> for KindVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl<T: EntityKind> FromIterator<T> for KindVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(Vec::from_iter(iter))
+    }
+}
+
+///
+/// A map keyed by entities with some given [`EntityKind`].
+///
+/// Only the [`Entity`] of each key is stored, so keys are hashed as cheaply as plain entities.
+///
+pub struct KindHashMap<T: EntityKind, V> {
+    map: HashMap<Entity, V>,
+    marker: PhantomData<T>,
+}
+
+impl<T: EntityKind, V> KindHashMap<T, V> {
+    ///
+    /// Inserts a value for the given [`EntityKind`] and returns the previous value, if any.
+    ///
+    pub fn insert(&mut self, kind: &T, value: V) -> Option<V> {
+        self.map.insert(kind.entity(), value)
+    }
+
+    ///
+    /// Returns the value of the given [`EntityKind`], if any.
+    ///
+    pub fn get(&self, kind: &T) -> Option<&V> {
+        self.map.get(&kind.entity())
+    }
+
+    ///
+    /// Returns the value of the given [`EntityKind`] mutably, if any.
+    ///
+    pub fn get_mut(&mut self, kind: &T) -> Option<&mut V> {
+        self.map.get_mut(&kind.entity())
+    }
+
+    ///
+    /// Removes the value of the given [`EntityKind`] and returns it, if any.
+    ///
+    pub fn remove(&mut self, kind: &T) -> Option<V> {
+        self.map.remove(&kind.entity())
+    }
+
+    ///
+    /// Returns true if this map contains a value for the given [`EntityKind`].
+    ///
+    pub fn contains_key(&self, kind: &T) -> bool {
+        self.map.contains_key(&kind.entity())
+    }
+
+    ///
+    /// Returns the number of entries in this map.
+    ///
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    ///
+    /// Returns true if this map is empty.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    ///
+    /// Iterates over all entries in this map.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (T, &V)> {
+        self.map.iter().map(|(entity, value)| {
+            // SAFE: Keys are only inserted from entities of matching kind
+            (unsafe { T::from_entity_unchecked(*entity) }, value)
+        })
+    }
+}
+
+impl<T: EntityKind, V> Default for KindHashMap<T, V> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+            marker: PhantomData,
+        }
+    }
+}
+
+///
+/// A [`Component`] which stores the [`TypeId`] of every [`EntityKind`] present on an [`Entity`].
+///
+/// This component is only maintained for kinds with [`KindPlugin::with_kind_set`] enabled.
+///
+#[derive(Component, Default, Debug)]
+pub struct KindSet {
+    kinds: HashSet<TypeId>,
+}
+
+impl KindSet {
+    ///
+    /// Returns true if the given [`EntityKind`] is in this [`KindSet`].
+    ///
+    pub fn contains<T: EntityKind>(&self) -> bool {
+        self.kinds.contains(&TypeId::of::<T>())
+    }
+
+    ///
+    /// Iterates over the [`TypeId`] of every [`EntityKind`] in this [`KindSet`].
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.kinds.iter().copied()
+    }
+}
+
+#[cfg(feature = "app")]
+fn update_kind_set<T: EntityKind>(
+    query: Query<Entity, AddedKind<T>>,
+    mut removed: RemovedComponents<Kind<T>>,
+    mut commands: Commands,
+) {
+    for entity in &query {
+        commands.add(move |world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if let Some(mut kind_set) = entity.get_mut::<KindSet>() {
+                kind_set.kinds.insert(TypeId::of::<T>());
+            } else {
+                let mut kind_set = KindSet::default();
+                kind_set.kinds.insert(TypeId::of::<T>());
+                entity.insert(kind_set);
+            }
+        });
+    }
+    for entity in removed.iter() {
+        commands.add(move |world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(entity) else {
+                return;
+            };
+            if entity.contains::<Kind<T>>() {
+                return;
+            }
+            if let Some(mut kind_set) = entity.get_mut::<KindSet>() {
+                kind_set.kinds.remove(&TypeId::of::<T>());
+            }
+        });
+    }
+}
+
+///
+/// A [`Component`] which marks an [`Entity`] as having a given [`EntityKind`].
+///
+/// This component may only be inserted using a [`KindBundle`], which guarantees the kind's invariants.
+///
+#[derive(Component)]
+pub struct Kind<T: EntityKind>(PhantomData<T>);
+
+impl<T: EntityKind> Kind<T> {
+    fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub mod prelude {
+    pub use crate::{
+        EntityKind, EntityKindCommands, EntityWithKind, InsertKind, KindBundle, KindCommands,
+        TryWithKind, WithKind,
+    };
+}
+
+pub mod utils {
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::system::CommandQueue;
+
+    ///
+    /// Extension trait to execute [`Commands`] on a [`World`].
+    ///
+    pub trait Execute {
+        fn execute<F: FnOnce(&World, Commands) -> R, R>(self, f: F) -> R;
+    }
+
+    impl Execute for &mut World {
+        fn execute<F: FnOnce(&World, Commands) -> R, R>(self, f: F) -> R {
+            let mut queue = CommandQueue::default();
+            let commands = Commands::new(&mut queue, self);
+            let result = f(self, commands);
+            queue.apply(self);
+            result
+        }
+    }
+}
+
+#[cfg(feature = "test-utils")]
+pub mod test_utils {
+    use bevy_ecs::prelude::*;
+
+    use crate::{assert_invariants, EntityKind};
+
+    ///
+    /// A [`World`] wrapper with convenience methods for testing [`EntityKind`] logic.
+    ///
+    #[derive(Default)]
+    pub struct TestApp {
+        world: World,
+    }
+
+    impl TestApp {
+        ///
+        /// Creates a new [`TestApp`] with an empty [`World`].
+        ///
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        ///
+        /// Spawns a new [`Entity`] with given [`EntityKind`] and returns it.
+        ///
+        #[track_caller]
+        pub fn spawn_kind<T: EntityKind>(&mut self, bundle: T::Bundle) -> T {
+            T::spawn_in(&mut self.world, bundle)
+        }
+
+        ///
+        /// Asserts that the given [`EntityKind`] has all of its components.
+        ///
+        pub fn assert_kind<T: EntityKind>(&self, kind: &T) {
+            assert_invariants::<T>(&self.world, kind.entity());
+        }
+
+        ///
+        /// Returns a [`Component`] of the given [`Entity`], if it exists.
+        ///
+        pub fn get_component<C: Component>(&self, entity: Entity) -> Option<&C> {
+            self.world.get::<C>(entity)
+        }
+
+        ///
+        /// Returns the underlying [`World`].
+        ///
+        pub fn world(&self) -> &World {
+            &self.world
+        }
+
+        ///
+        /// Returns the underlying [`World`] as mutable.
+        ///
+        pub fn world_mut(&mut self) -> &mut World {
+            &mut self.world
         }
     }
 }
@@ -0,0 +1,180 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use bevy::prelude::*;
+use bevy_kindly::prelude::*;
+use bevy_kindly::utils::Execute;
+use bevy_kindly::{check_invariants, KindWorld};
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Clearance)]
+struct Agent(Entity);
+
+#[derive(Component, Default)]
+struct Position(i32);
+
+#[derive(Component)]
+struct Speed(i32);
+
+#[derive(Component)]
+struct Clearance;
+
+#[derive(Component)]
+struct Destination(i32);
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Wheels)]
+struct Vehicle(Entity);
+
+#[derive(Component)]
+struct Wheels;
+
+#[derive(EntityKind)]
+#[components(Clearance, Mass)]
+struct Obstacle(Entity);
+
+#[derive(Component)]
+struct Mass;
+
+#[derive(EntityKind)]
+#[components(Speed, Counted)]
+struct Probe(Entity);
+
+#[derive(Component)]
+struct Counted(Arc<AtomicUsize>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn world() -> World {
+    let mut world = World::new();
+    world
+        .register_kind::<Agent>()
+        .register_kind::<Vehicle>()
+        .register_kind::<Obstacle>()
+        .register_kind::<Probe>();
+    world
+}
+
+trait NavigateTo {
+    fn navigate_to(self, position: i32) -> Self;
+}
+
+impl NavigateTo for &mut EntityKindCommands<'_, '_, '_, Agent> {
+    fn navigate_to(self, position: i32) -> Self {
+        self.insert(Destination(position))
+    }
+}
+
+#[test]
+fn spawn_with_kind_and_accepts_fluent_extensions() {
+    let mut world = World::new();
+    let entity = world.execute(|_, mut commands| {
+        commands.spawn_with_kind_and::<Agent, _>((Speed(1), Clearance), |agent| {
+            agent.navigate_to(5).insert(Position(2))
+        })
+    });
+
+    let agent: Agent = world.entity(entity).try_with_kind().unwrap();
+    assert_eq!(world.get::<Destination>(agent.entity()).unwrap().0, 5);
+    assert_eq!(world.get::<Position>(agent.entity()).unwrap().0, 2);
+}
+
+#[test]
+fn replace_kind_keeps_shared_components() {
+    let mut world = world();
+    let agent = Agent::spawn_in(&mut world, (Speed(1), Clearance));
+    world.get_mut::<Position>(agent.entity()).unwrap().0 = 7;
+
+    world.execute(|_, mut commands| {
+        commands
+            .with_kind(&agent)
+            .replace_kind::<Vehicle>((Speed(3), Wheels));
+    });
+
+    let entity = world.entity(agent.entity());
+    assert!(entity.try_with_kind::<Vehicle>().is_some());
+    assert!(entity.try_with_kind::<Agent>().is_none());
+    assert_eq!(entity.get::<Position>().unwrap().0, 7);
+    assert_eq!(entity.get::<Speed>().unwrap().0, 3);
+    assert!(!entity.contains::<Clearance>());
+}
+
+#[test]
+fn replace_kind_keeps_components_of_other_kinds() {
+    let mut world = world();
+    let agent = Agent::spawn_in(&mut world, (Speed(1), Clearance));
+    world
+        .entity_mut(agent.entity())
+        .insert(KindBundle::<Obstacle>::new((Clearance, Mass)));
+
+    world.execute(|_, mut commands| {
+        commands
+            .with_kind(&agent)
+            .replace_kind::<Vehicle>((Speed(3), Wheels));
+    });
+
+    let entity = world.entity(agent.entity());
+    assert!(entity.contains::<Clearance>());
+    assert!(check_invariants::<Obstacle>(&world, agent.entity()).is_ok());
+    assert!(check_invariants::<Vehicle>(&world, agent.entity()).is_ok());
+}
+
+#[test]
+fn replace_kind_drops_removed_components_once() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = world();
+    let probe = Probe::spawn_in(&mut world, (Speed(1), Counted(drops.clone())));
+
+    world.execute(|_, mut commands| {
+        commands
+            .with_kind(&probe)
+            .replace_kind::<Vehicle>((Speed(3), Wheels));
+    });
+
+    assert!(!world.entity(probe.entity()).contains::<Counted>());
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn insert_kind_preserving_keeps_default_components() {
+    let mut world = world();
+    let entity = world.spawn(Position(9)).id();
+
+    world.execute(|_, mut commands| {
+        commands
+            .entity(entity)
+            .insert_kind_preserving::<Agent>((Speed(1), Clearance));
+    });
+
+    assert!(check_invariants::<Agent>(&world, entity).is_ok());
+    assert_eq!(world.get::<Position>(entity).unwrap().0, 9);
+}
+
+#[test]
+fn insert_with_only_creates_components_if_needed() {
+    let mut world = world();
+    let mut created = 0;
+    let agent = world.execute(|_, mut commands| {
+        commands
+            .spawn_with_kind::<Agent>((Speed(1), Clearance))
+            .insert_with(false, || {
+                created += 1;
+                Destination(1)
+            })
+            .insert_with(true, || Wheels)
+            .get()
+    });
+
+    assert_eq!(created, 0);
+    assert!(!world.entity(agent.entity()).contains::<Destination>());
+    assert!(world.entity(agent.entity()).contains::<Wheels>());
+}
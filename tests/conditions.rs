@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use bevy_kindly::prelude::*;
+use bevy_kindly::{any_with_kind, no_entities_with_kind};
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Clearance)]
+struct Agent(Entity);
+
+#[derive(Component, Default)]
+struct Position;
+
+#[derive(Component)]
+struct Speed;
+
+#[derive(Component)]
+struct Clearance;
+
+#[derive(Resource, Default)]
+struct Runs {
+    any: usize,
+    none: usize,
+}
+
+fn count_any(mut runs: ResMut<Runs>) {
+    runs.any += 1;
+}
+
+fn count_none(mut runs: ResMut<Runs>) {
+    runs.none += 1;
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.init_resource::<Runs>().add_systems(
+        Update,
+        (
+            count_any.run_if(any_with_kind::<Agent>()),
+            count_none.run_if(no_entities_with_kind::<Agent>()),
+        ),
+    );
+    app
+}
+
+fn runs(app: &App) -> (usize, usize) {
+    let runs = app.world.resource::<Runs>();
+    (runs.any, runs.none)
+}
+
+#[test]
+fn conditions_without_entities() {
+    let mut app = app();
+    app.update();
+    assert_eq!(runs(&app), (0, 1));
+}
+
+#[test]
+fn conditions_toggle_on_spawn_and_despawn() {
+    let mut app = app();
+
+    let agent = Agent::spawn_in(&mut app.world, (Speed, Clearance));
+    app.update();
+    assert_eq!(runs(&app), (1, 0));
+
+    app.world.despawn(agent.entity());
+    app.update();
+    assert_eq!(runs(&app), (1, 1));
+
+    Agent::spawn_in(&mut app.world, (Speed, Clearance));
+    app.update();
+    assert_eq!(runs(&app), (2, 1));
+}
+
+#[test]
+fn conditions_ignore_entities_without_kind() {
+    let mut app = app();
+    app.world.spawn((Position, Speed, Clearance));
+    app.update();
+    assert_eq!(runs(&app), (0, 1));
+}
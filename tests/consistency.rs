@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_kindly::prelude::*;
+use bevy_kindly::utils::Execute;
+use bevy_kindly::{kind_name, AsKind, Kind, KindWorld};
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Clearance)]
+struct Agent(Entity);
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Wheels)]
+struct Vehicle(Entity);
+
+#[derive(EntityKind)]
+#[components(Clearance, Mass)]
+struct Obstacle(Entity);
+
+#[derive(Component, Default)]
+struct Position;
+
+#[derive(Component)]
+struct Speed;
+
+#[derive(Component)]
+struct Clearance;
+
+#[derive(Component)]
+struct Wheels;
+
+#[derive(Component)]
+struct Mass;
+
+trait TestKind: EntityKind {
+    fn bundle() -> Self::Bundle;
+}
+
+impl TestKind for Agent {
+    fn bundle() -> Self::Bundle {
+        (Speed, Clearance)
+    }
+}
+
+impl TestKind for Vehicle {
+    fn bundle() -> Self::Bundle {
+        (Speed, Wheels)
+    }
+}
+
+impl TestKind for Obstacle {
+    fn bundle() -> Self::Bundle {
+        (Clearance, Mass)
+    }
+}
+
+const KINDS: usize = 3;
+const ENTITIES: usize = 8;
+const STEPS: usize = 200;
+
+/// Expands `$body` with `$kind` as the test kind at the given index.
+macro_rules! with_kind_index {
+    ($index:expr, $kind:ident => $body:expr) => {
+        match $index {
+            0 => {
+                type $kind = Agent;
+                $body
+            }
+            1 => {
+                type $kind = Vehicle;
+                $body
+            }
+            _ => {
+                type $kind = Obstacle;
+                $body
+            }
+        }
+    };
+}
+
+/// Minimal xorshift generator, so that failures are reproducible from the seed alone.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+fn insert_kind<T: TestKind>(world: &mut World, entity: Entity) {
+    world.execute(|_, mut commands| {
+        commands.entity(entity).insert_kind::<T>(T::bundle());
+    });
+}
+
+fn retain_kind<T: TestKind>(world: &mut World, entity: Entity) {
+    world.execute(|_, mut commands| {
+        // SAFE: Only called if the model has this kind
+        unsafe { commands.entity(entity).as_kind::<T>() }.retain_kind();
+    });
+}
+
+fn replace_kind<T: TestKind, U: TestKind>(world: &mut World, entity: Entity) {
+    world.execute(|_, mut commands| {
+        // SAFE: Only called if the model has this kind
+        unsafe { commands.entity(entity).as_kind::<T>() }.replace_kind::<U>(U::bundle());
+    });
+}
+
+fn force_remove<T: TestKind>(world: &mut World, entity: Entity, component: usize) {
+    world.execute(|_, mut commands| {
+        // SAFE: Only called if the model has this kind
+        let mut kind = unsafe { commands.entity(entity).as_kind::<T>() };
+        match component {
+            0 => kind.force_remove::<Position>(),
+            1 => kind.force_remove::<Speed>(),
+            2 => kind.force_remove::<Clearance>(),
+            3 => kind.force_remove::<Wheels>(),
+            _ => kind.force_remove::<Mass>(),
+        };
+    });
+}
+
+fn assert_consistent<T: TestKind>(
+    world: &mut World,
+    entities: &[Entity],
+    model: &[[bool; KINDS]],
+    index: usize,
+) {
+    let mut query = world.query_filtered::<Entity, WithKind<T>>();
+    let filtered: HashSet<Entity> = query.iter(world).collect();
+    let mut query = world.query::<EntityWithKind<T>>();
+    let items: HashSet<Entity> = query.iter(world).map(|item| item.entity()).collect();
+
+    for (entity, kinds) in entities.iter().zip(model) {
+        let entity_ref = world.entity(*entity);
+        let has_marker = entity_ref.contains::<Kind<T>>();
+        assert_eq!(
+            has_marker,
+            kinds[index],
+            "{entity:?} marker of {}",
+            kind_name::<T>()
+        );
+        assert_eq!(entity_ref.try_with_kind::<T>().is_some(), has_marker);
+        assert_eq!(entity_ref.with_kind_result::<T>().is_ok(), has_marker);
+        assert_eq!(filtered.contains(entity), has_marker);
+        assert_eq!(items.contains(entity), has_marker);
+    }
+}
+
+fn run(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut world = World::new();
+    world
+        .register_kind::<Agent>()
+        .register_kind::<Vehicle>()
+        .register_kind::<Obstacle>();
+    let entities: Vec<Entity> = (0..ENTITIES).map(|_| world.spawn_empty().id()).collect();
+    let mut model = [[false; KINDS]; ENTITIES];
+
+    for _ in 0..STEPS {
+        let index = rng.next(ENTITIES);
+        let entity = entities[index];
+        let kinds = &mut model[index];
+        let kind = rng.next(KINDS);
+        match rng.next(4) {
+            0 => {
+                with_kind_index!(kind, T => insert_kind::<T>(&mut world, entity));
+                kinds[kind] = true;
+            }
+            1 if kinds[kind] => {
+                with_kind_index!(kind, T => retain_kind::<T>(&mut world, entity));
+                *kinds = [false; KINDS];
+                kinds[kind] = true;
+            }
+            2 if kinds[kind] => {
+                let other = rng.next(KINDS);
+                with_kind_index!(kind, T => with_kind_index!(other, U => replace_kind::<T, U>(&mut world, entity)));
+                kinds[kind] = false;
+                kinds[other] = true;
+            }
+            3 if kinds[kind] => {
+                let component = rng.next(5);
+                with_kind_index!(kind, T => force_remove::<T>(&mut world, entity, component));
+            }
+            _ => continue,
+        }
+
+        for kind in 0..KINDS {
+            with_kind_index!(kind, T => assert_consistent::<T>(&mut world, &entities, &model, kind));
+        }
+    }
+}
+
+#[test]
+fn kind_markers_match_random_operations() {
+    for seed in 1..=32 {
+        run(seed);
+    }
+}
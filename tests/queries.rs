@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use bevy_kindly::kind_query_state;
+use bevy_kindly::prelude::*;
+
+#[derive(EntityKind)]
+#[default_components(Items)]
+#[components(Name, Capacity)]
+struct Container(Entity);
+
+#[derive(Component, Default)]
+struct Items;
+
+#[derive(Component)]
+struct Capacity;
+
+#[derive(EntityKind)]
+#[default_components(Friends)]
+#[components(Name, Age)]
+struct Person(Entity);
+
+#[derive(Component, Default)]
+struct Friends;
+
+#[derive(Component)]
+struct Age;
+
+#[derive(EntityKind)]
+#[default_components(Position)]
+#[components(Speed, Clearance)]
+struct Agent(Entity);
+
+#[derive(Component, Default)]
+struct Position(i32);
+
+#[derive(Component)]
+struct Speed(i32);
+
+#[derive(Component)]
+struct Clearance;
+
+#[test]
+fn kind_query_state_iterates_all_kinds() {
+    let mut world = World::new();
+    let containers: Vec<Container> = (0..3)
+        .map(|i| Container::spawn_in(&mut world, (Name::new(format!("Box {i}")), Capacity)))
+        .collect();
+    world.spawn((Name::new("Not a Box"), Capacity, Items));
+
+    let mut query = kind_query_state::<Container>(&mut world);
+    let found: Vec<Entity> = query.iter(&world).map(|item| item.entity()).collect();
+
+    assert_eq!(found.len(), containers.len());
+    for container in &containers {
+        assert!(found.contains(&container.entity()));
+    }
+}
+
+#[test]
+fn with_kind_composes_with_or() {
+    let mut world = World::new();
+    let person = Person::spawn_in(&mut world, (Name::new("Alice"), Age));
+    let agent = Agent::spawn_in(&mut world, (Speed(1), Clearance));
+    Container::spawn_in(&mut world, (Name::new("Box"), Capacity));
+
+    let mut query = world.query_filtered::<Entity, Or<(WithKind<Person>, WithKind<Agent>)>>();
+    let found: Vec<Entity> = query.iter(&world).collect();
+
+    assert_eq!(found.len(), 2);
+    assert!(found.contains(&person.entity()));
+    assert!(found.contains(&agent.entity()));
+}
+
+#[test]
+fn with_kind_composes_with_and() {
+    let mut world = World::new();
+    let entity = world
+        .spawn((
+            KindBundle::<Person>::new((Name::new("Bob"), Age)),
+            KindBundle::<Agent>::new((Speed(1), Clearance)),
+        ))
+        .id();
+    Person::spawn_in(&mut world, (Name::new("Alice"), Age));
+    Agent::spawn_in(&mut world, (Speed(1), Clearance));
+
+    let mut query = world.query_filtered::<Entity, (WithKind<Person>, WithKind<Agent>)>();
+    let found: Vec<Entity> = query.iter(&world).collect();
+
+    assert_eq!(found, [entity]);
+}
+
+fn move_agents(mut query: Query<(EntityWithKind<Agent>, &Speed, &mut Position)>) {
+    for (_agent, speed, mut position) in &mut query {
+        position.0 += speed.0;
+    }
+}
+
+#[test]
+fn entity_with_kind_in_mutable_system_query() {
+    let mut world = World::new();
+    let agent = Agent::spawn_in(&mut world, (Speed(2), Clearance));
+    let other = world.spawn((Speed(2), Position(0))).id();
+
+    let mut schedule = Schedule::default();
+    schedule.add_systems(move_agents);
+    schedule.run(&mut world);
+    schedule.run(&mut world);
+
+    assert_eq!(world.get::<Position>(agent.entity()).unwrap().0, 4);
+    assert_eq!(world.get::<Position>(other).unwrap().0, 0);
+}
+
+#[test]
+fn entity_with_kind_in_mutable_query_state() {
+    let mut world = World::new();
+    let agent = Agent::spawn_in(&mut world, (Speed(3), Clearance));
+
+    let mut query = world.query::<(EntityWithKind<Agent>, &mut Position)>();
+    for (item, mut position) in query.iter_mut(&mut world) {
+        assert!(item == agent);
+        position.0 = 5;
+    }
+
+    // `EntityWithKind` is read-only, so this query may be iterated without mutable world access
+    let mut query = world.query::<(EntityWithKind<Agent>, &Position)>();
+    let positions: Vec<i32> = query.iter(&world).map(|(_, position)| position.0).collect();
+    assert_eq!(positions, [5]);
+}